@@ -0,0 +1,12 @@
+use geomutil_util::Point2;
+
+pub fn random_points(n: usize) -> Vec<Point2<f64>> {
+    let mut state = 42u64;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        <f64 as From<u32>>::from(u32::try_from(state >> 40).unwrap_or_default()) / 16_777_216.0
+    };
+    (0..n).map(|_| Point2::from([next(), next()])).collect()
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use geomutil_triangulation::{TriangulationAlgorithm, TriangulationOptions, triangulate_with};
+
+use common::random_points;
+
+fn triangulate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("triangulate");
+    group.sample_size(10);
+    for n in [10_000, 100_000, 1_000_000, 10_000_000] {
+        let points = random_points(n);
+        let mut algorithms = vec![TriangulationAlgorithm::SweepHull];
+        if cfg!(feature = "rayon") {
+            algorithms.push(TriangulationAlgorithm::Parallel);
+        }
+        if n <= 10_000 {
+            algorithms.push(TriangulationAlgorithm::BowyerWatson);
+        }
+        for algorithm in algorithms {
+            let id = BenchmarkId::new(format!("{algorithm:?}"), n);
+            group.bench_with_input(id, &points, |b, points| {
+                b.iter(|| {
+                    triangulate_with(points.iter().copied(), TriangulationOptions::new(algorithm))
+                });
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, triangulate);
+criterion_main!(benches);
@@ -1,43 +1,40 @@
 use anyhow::Result;
 use geomutil_triangulation::alpha_shape_2d;
-use geomutil_util::{Point2D, points_bounding_box};
+use geomutil_util::Point2;
 use plotters::prelude::*;
 
 fn main() -> Result<()> {
     let points = [
-        Point2D::new(0.0, 0.0),
-        Point2D::new(1.0, 0.0),
-        Point2D::new(1.0, 1.0),
-        Point2D::new(0.0, 1.0),
-        Point2D::new(3.0, 0.0),
-        Point2D::new(4.0, 0.0),
-        Point2D::new(4.0, 1.0),
-        Point2D::new(3.0, 1.0),
+        Point2::from([0.0, 0.0]),
+        Point2::from([1.0, 0.0]),
+        Point2::from([1.0, 1.0]),
+        Point2::from([0.0, 1.0]),
+        Point2::from([3.0, 0.0]),
+        Point2::from([4.0, 0.0]),
+        Point2::from([4.0, 1.0]),
+        Point2::from([3.0, 1.0]),
     ];
-    let shapes = alpha_shape_2d(&points, 1.25).unwrap();
+    let shapes = alpha_shape_2d(points, 1.25).unwrap();
     let root = BitMapBackend::new("triangles.png", (800, 600)).into_drawing_area();
     root.fill(&WHITE)?;
 
-    let (low_boundary, up_boundary) = points_bounding_box(&points).unwrap();
+    let bbox = Point2::bounding_box(points).unwrap();
     println!("{}", shapes.len());
 
     // 2. Define the chart context
     // We'll set up a coordinate system from (0,0) to (100, 100)
     let mut chart = ChartBuilder::on(&root).margin(10).build_cartesian_2d(
-        low_boundary.x - 1.0..up_boundary.x + 1.0,
-        low_boundary.y - 1.0..up_boundary.y + 1.0,
+        bbox.lower.x - 1.0..bbox.upper.x + 1.0,
+        bbox.lower.y - 1.0..bbox.upper.y + 1.0,
     )?; // Use f32 for the coordinate range
     chart.configure_mesh().draw()?;
 
-    for triangle in shapes.iter().map(|s| s.triangles.clone()).flatten() {
+    for triangle in shapes.iter().flat_map(|s| s.triangles.clone()) {
         // Optionally, draw the outline of the triangle
         let line_series = LineSeries::new(
-            vec![
-                triangle.a.xy(),
-                triangle.b.xy(),
-                triangle.c.xy(),
-                triangle.a.xy(),
-            ], // Close the loop
+            [triangle.a, triangle.b, triangle.c, triangle.a] // Close the loop
+                .into_iter()
+                .map(|p| (p.x, p.y)),
             BLACK.stroke_width(2), // Black outline, 2 pixels thick
         );
 
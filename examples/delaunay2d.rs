@@ -30,16 +30,15 @@ fn main() -> Result<()> {
 
     // 2. Define the chart context
     // We'll set up a coordinate system from (0,0) to (100, 100)
-    let mut chart = ChartBuilder::on(&root).margin(10).build_cartesian_2d(
-        bbox.lower().x..bbox.upper().x,
-        bbox.lower().y..bbox.upper().y,
-    )?; // Use f32 for the coordinate range
+    let mut chart = ChartBuilder::on(&root)
+        .margin(10)
+        .build_cartesian_2d(bbox.lower.x..bbox.upper.x, bbox.lower.y..bbox.upper.y)?; // Use f32 for the coordinate range
     chart.configure_mesh().draw()?;
 
     for triangle in triangulation
         .triangles
         .iter()
-        .chain(&[triangulation.bounding_triangle])
+        .chain([&triangulation.bounding_triangle])
     {
         let line_series = LineSeries::new(
             [triangle.a, triangle.b, triangle.c, triangle.a]
@@ -0,0 +1,73 @@
+// One-shot timing of the triangulation backends on uniform random points.
+// Every backend must produce 2n - 2 - h triangles for n points with h on the hull.
+//
+//   cargo run --release --features rayon --example triangulate_benchmark -- 10000000
+//
+// Repeatable measurements up to 10M points: `cargo bench --features rayon`.
+
+#[path = "../benches/common/mod.rs"]
+mod common;
+
+use std::{env, time::Instant};
+
+use anyhow::{Result, ensure};
+use geomutil_triangulation::{TriangulationAlgorithm, TriangulationOptions, triangulate_with};
+use geomutil_util::Point2;
+
+use common::random_points;
+
+fn hull_size(points: &[Point2<f64>]) -> usize {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    sorted.dedup();
+    let mut hull: Vec<Point2<f64>> = Vec::new();
+    for chain in [sorted.clone(), sorted.into_iter().rev().collect()] {
+        let start = hull.len();
+        for p in chain {
+            while hull.len() >= start + 2 {
+                let (o, a) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+                if (a - o).cross(p - o) > 0.0 {
+                    break;
+                }
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull.pop();
+    }
+    hull.len()
+}
+
+fn main() -> Result<()> {
+    let count = env::args()
+        .nth(1)
+        .map(|arg| arg.parse())
+        .transpose()?
+        .unwrap_or(1_000_000usize);
+
+    let points = random_points(count);
+
+    let mut algorithms = vec![TriangulationAlgorithm::SweepHull];
+    #[cfg(feature = "rayon")]
+    algorithms.push(TriangulationAlgorithm::Parallel);
+    if count <= 20_000 {
+        algorithms.push(TriangulationAlgorithm::BowyerWatson);
+    }
+    let unique = Point2::unique(points.iter().copied()).len();
+    let expected = 2 * unique - 2 - hull_size(&points);
+    for algorithm in algorithms {
+        let start = Instant::now();
+        let triangulation =
+            triangulate_with(points.iter().copied(), TriangulationOptions::new(algorithm)).unwrap();
+        println!(
+            "{algorithm:?}: {count} points, {} triangles in {:.2?}",
+            triangulation.triangles.len(),
+            start.elapsed()
+        );
+        ensure!(
+            triangulation.triangles.len() == expected,
+            "{algorithm:?}: expected {expected} triangles"
+        );
+    }
+    Ok(())
+}
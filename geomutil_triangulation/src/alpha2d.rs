@@ -1,15 +1,15 @@
-use crate::triangulate;
-use geomutil_util::{Edge2, Point2, Shape2D, Triangle};
-use std::collections::{HashMap, VecDeque};
+use crate::{locate::triangle_neighbours, triangulate};
+use geomutil_util::{Float, Point2, Shape2D, Triangle};
+use std::collections::VecDeque;
 
-struct AlphaShape2D {
-    alpha: f32,
-    triangles: Vec<Triangle>,
+struct AlphaShape2D<T: Float> {
+    alpha: T,
+    triangles: Vec<Triangle<T>>,
     connections: Vec<[Option<usize>; 3]>,
 }
 
-impl AlphaShape2D {
-    const fn new(triangles: Vec<Triangle>, alpha: f32) -> Self {
+impl<T: Float> AlphaShape2D<T> {
+    const fn new(triangles: Vec<Triangle<T>>, alpha: T) -> Self {
         Self {
             alpha,
             triangles,
@@ -18,42 +18,19 @@ impl AlphaShape2D {
     }
 
     fn prune(&mut self) {
-        let r_sq = (1.0 / self.alpha) * (1.0 / self.alpha);
+        let r_sq = (T::one() / self.alpha) * (T::one() / self.alpha);
         for i in (0..self.triangles.len()).rev() {
             if self.triangles[i].circumcircle_radius_squared() > r_sq {
                 self.triangles.swap_remove(i);
             }
         }
-        self.connections = vec![[None; 3]; self.triangles.len()];
     }
 
-    fn build_connections_graph(&self) {
-        let mut adjacent_edges: HashMap<Edge2, [Option<usize>; 2]> =
-            HashMap::with_capacity(self.triangles.len() * 2);
-        for (i, t) in self.triangles.iter().enumerate() {
-            for e in t.edges() {
-                let e = e.canonical();
-                adjacent_edges
-                    .entry(e)
-                    .and_modify(|adjacent: &mut _| adjacent[1] = Some(i))
-                    .or_insert([Some(i), None]);
-            }
-        }
-        // for (_, neighbours) in adjacent_edges.drain() {
-        //     for (i, j) in [
-        //         (neighbours[0], neighbours[1]),
-        //         (neighbours[1], neighbours[0]),
-        //     ] {
-        //         if let Some(i) = i {
-        //             if let Some(neighbour) = self.connections[i].iter_mut().find(|a| a.is_none()) {
-        //                 *neighbour = j;
-        //             }
-        //         }
-        //     }
-        // }
+    fn build_connections_graph(&mut self) {
+        self.connections = triangle_neighbours(&self.triangles);
     }
 
-    fn shapes(&mut self) -> Vec<Shape2D> {
+    fn shapes(&mut self) -> Vec<Shape2D<T>> {
         self.prune();
         self.build_connections_graph();
         let mut queue = VecDeque::new();
@@ -63,13 +40,14 @@ impl AlphaShape2D {
             if visited[i] {
                 continue;
             }
+            visited[i] = true;
             queue.push_back(i);
             let mut shape = Vec::new();
             while let Some(i) = queue.pop_front() {
-                visited[i] = true;
                 shape.push(self.triangles[i].clone());
                 for neigh_i in self.connections[i].into_iter().flatten() {
                     if !visited[neigh_i] {
+                        visited[neigh_i] = true;
                         queue.push_back(neigh_i);
                     }
                 }
@@ -80,10 +58,10 @@ impl AlphaShape2D {
     }
 }
 
-pub fn alpha_shape_2d(
-    points: impl IntoIterator<Item = Point2>,
-    alpha: f32,
-) -> Option<Vec<Shape2D>> {
+pub fn alpha_shape_2d<T: Float>(
+    points: impl IntoIterator<Item = Point2<T>>,
+    alpha: T,
+) -> Option<Vec<Shape2D<T>>> {
     let triangulation = triangulate(points)?;
     let mut alpha_shape = AlphaShape2D::new(triangulation.triangles, alpha);
     let shapes = alpha_shape.shapes();
@@ -0,0 +1,282 @@
+use geomutil_util::{Float, Point2};
+
+use crate::{
+    TriangulationFloat, delaunay_edges, euclidean_minimum_spanning_tree,
+    graph::{DisjointSet, sorted_indices},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EdgeClass {
+    Short,
+    Long,
+    Other,
+}
+
+fn labels<T: Float>(
+    points: &[Point2<T>],
+    edges: impl IntoIterator<Item = (usize, usize)>,
+) -> Vec<usize> {
+    let mut components = DisjointSet::new(points.len());
+    for pair in sorted_indices(points).windows(2) {
+        if points[pair[0]] == points[pair[1]] {
+            components.union(pair[0], pair[1]);
+        }
+    }
+    for (i, j) in edges {
+        components.union(i, j);
+    }
+    let mut roots = vec![usize::MAX; points.len()];
+    let mut count = 0;
+    (0..points.len())
+        .map(|i| {
+            let root = components.find(i);
+            if roots[root] == usize::MAX {
+                roots[root] = count;
+                count += 1;
+            }
+            roots[root]
+        })
+        .collect()
+}
+
+fn components(
+    n: usize,
+    edges: impl IntoIterator<Item = (usize, usize)>,
+) -> (Vec<usize>, Vec<usize>) {
+    let mut set = DisjointSet::new(n);
+    for (i, j) in edges {
+        set.union(i, j);
+    }
+    let roots = (0..n).map(|i| set.find(i)).collect::<Vec<_>>();
+    let mut sizes = vec![0; n];
+    for &root in &roots {
+        sizes[root] += 1;
+    }
+    (roots, sizes)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn mean<T: Float>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    let (sum, count) = values
+        .into_iter()
+        .fold((T::zero(), 0usize), |(sum, count), value| {
+            (sum + value, count + 1)
+        });
+    (count > 0).then(|| sum / T::from(count as f64))
+}
+
+#[must_use]
+pub fn cluster_by_edge_length<T: TriangulationFloat>(
+    points: &[Point2<T>],
+    max_length: T,
+) -> Vec<usize> {
+    let edges = delaunay_edges(points)
+        .into_iter()
+        .filter(|&(i, j)| points[i].distance(points[j]) <= max_length);
+    labels(points, edges)
+}
+
+#[must_use]
+pub fn single_linkage_clusters<T: TriangulationFloat>(
+    points: &[Point2<T>],
+    clusters: usize,
+) -> Vec<usize> {
+    let mut tree = euclidean_minimum_spanning_tree(points);
+    tree.sort_by(|&(a, b), &(c, d)| {
+        points[a]
+            .distance_squared(points[b])
+            .total_cmp(&points[c].distance_squared(points[d]))
+    });
+    tree.truncate((tree.len() + 1).saturating_sub(clusters.max(1)));
+    labels(points, tree)
+}
+
+#[must_use]
+pub fn autoclust<T: TriangulationFloat>(points: &[Point2<T>]) -> Vec<usize> {
+    let n = points.len();
+    let edges = delaunay_edges(points);
+    let lengths = edges
+        .iter()
+        .map(|&(i, j)| points[i].distance(points[j]))
+        .collect::<Vec<_>>();
+    let mut incident = vec![Vec::new(); n];
+    for (e, &(i, j)) in edges.iter().enumerate() {
+        incident[i].push(e);
+        incident[j].push(e);
+    }
+
+    let local_means = incident
+        .iter()
+        .map(|es| mean(es.iter().map(|&e| lengths[e])).unwrap_or_else(T::zero))
+        .collect::<Vec<_>>();
+    let local_deviations = incident
+        .iter()
+        .zip(&local_means)
+        .filter_map(|(es, &m)| mean(es.iter().map(|&e| (lengths[e] - m).powi(2))))
+        .map(T::sqrt)
+        .collect::<Vec<_>>();
+    let mean_deviation = mean(local_deviations).unwrap_or_else(T::zero);
+
+    let classes = edges
+        .iter()
+        .zip(&lengths)
+        .map(|(&(i, j), &length)| {
+            if length > local_means[i] + mean_deviation || length > local_means[j] + mean_deviation
+            {
+                EdgeClass::Long
+            } else if length < local_means[i] - mean_deviation
+                || length < local_means[j] - mean_deviation
+            {
+                EdgeClass::Short
+            } else {
+                EdgeClass::Other
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut kept = classes
+        .iter()
+        .map(|&class| class == EdgeClass::Other)
+        .collect::<Vec<_>>();
+
+    let (roots, sizes) = components(
+        n,
+        edges
+            .iter()
+            .zip(&kept)
+            .filter(|(_, k)| **k)
+            .map(|(e, _)| *e),
+    );
+    for v in 0..n {
+        let short = incident[v]
+            .iter()
+            .copied()
+            .filter(|&e| classes[e] == EdgeClass::Short)
+            .map(|e| {
+                let (i, j) = edges[e];
+                (e, roots[if i == v { j } else { i }])
+            })
+            .filter(|&(_, root)| sizes[root] > 1)
+            .collect::<Vec<_>>();
+        let target = if sizes[roots[v]] > 1 {
+            Some(roots[v])
+        } else {
+            short
+                .iter()
+                .map(|&(_, root)| root)
+                .max_by(|&a, &b| sizes[a].cmp(&sizes[b]).then(b.cmp(&a)))
+        };
+        for (e, root) in short {
+            if Some(root) == target {
+                kept[e] = true;
+            }
+        }
+    }
+
+    let mut adjacent = vec![Vec::new(); n];
+    for (e, &(i, j)) in edges.iter().enumerate() {
+        if kept[e] {
+            adjacent[i].push(e);
+            adjacent[j].push(e);
+        }
+    }
+    let mut removed = Vec::new();
+    for v in 0..n {
+        let mut neighbourhood = adjacent[v].clone();
+        for &e in &adjacent[v] {
+            let (i, j) = edges[e];
+            neighbourhood.extend(&adjacent[if i == v { j } else { i }]);
+        }
+        neighbourhood.sort_unstable();
+        neighbourhood.dedup();
+        let Some(local_mean) = mean(neighbourhood.iter().map(|&e| lengths[e])) else {
+            continue;
+        };
+        removed.extend(
+            adjacent[v]
+                .iter()
+                .copied()
+                .filter(|&e| lengths[e] > local_mean + mean_deviation),
+        );
+    }
+    for e in removed {
+        kept[e] = false;
+    }
+
+    labels(
+        points,
+        edges
+            .into_iter()
+            .zip(kept)
+            .filter(|(_, k)| *k)
+            .map(|(e, _)| e),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blob(x: f64, y: f64, spacing: f64, size: u8) -> impl Iterator<Item = Point2<f64>> {
+        let coordinate = |i: u8| <f64 as From<u8>>::from(i);
+        (0..size).flat_map(move |i| {
+            (0..size).map(move |j| {
+                Point2::from([
+                    spacing.mul_add(coordinate(i), x),
+                    spacing.mul_add(coordinate(j) + coordinate(i % 2) * 0.5, y),
+                ])
+            })
+        })
+    }
+
+    fn cluster_count(labels: &[usize]) -> usize {
+        labels.iter().max().map_or(0, |max| max + 1)
+    }
+
+    fn two_blobs() -> Vec<Point2<f64>> {
+        let mut points = blob(0.0, 0.0, 1.0, 5).collect::<Vec<_>>();
+        points.extend(blob(20.0, 3.0, 1.0, 5));
+        points.push(Point2::from([20.0, 3.0]));
+        points
+    }
+
+    fn assert_two_blobs(labels: &[usize]) {
+        assert_eq!(cluster_count(labels), 2);
+        assert!(labels[..25].iter().all(|&label| label == 0));
+        assert!(labels[25..].iter().all(|&label| label == 1));
+    }
+
+    #[test]
+    fn test_cluster_by_edge_length() {
+        let points = two_blobs();
+        assert_two_blobs(&cluster_by_edge_length(&points, 1.5));
+        assert_eq!(cluster_count(&cluster_by_edge_length(&points, 100.0)), 1);
+        assert_eq!(
+            cluster_count(&cluster_by_edge_length(&points, 0.5)),
+            points.len() - 1
+        );
+    }
+
+    #[test]
+    fn test_single_linkage_clusters() {
+        let points = two_blobs();
+        assert_two_blobs(&single_linkage_clusters(&points, 2));
+        assert_eq!(cluster_count(&single_linkage_clusters(&points, 1)), 1);
+        assert_eq!(cluster_count(&single_linkage_clusters(&points, 0)), 1);
+        assert_eq!(
+            cluster_count(&single_linkage_clusters(&points, 1000)),
+            points.len() - 1
+        );
+    }
+
+    #[test]
+    fn test_autoclust() {
+        assert_two_blobs(&autoclust(&two_blobs()));
+
+        let mut points = blob(0.0, 0.0, 0.25, 8).collect::<Vec<_>>();
+        points.extend(blob(6.0, 0.0, 1.0, 6));
+        let labels = autoclust(&points);
+        assert_eq!(cluster_count(&labels), 2);
+        assert!(labels[..64].iter().all(|&label| label == 0));
+        assert!(labels[64..].iter().all(|&label| label == 1));
+    }
+}
@@ -0,0 +1,363 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use geomutil_util::{Float, Location, Point2, Polygon, locate_in_ring, ring_signed_area};
+
+use crate::{Triangulation2, interpolate::VertexValues};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Isoline<T: Float> {
+    pub level: T,
+    pub points: Vec<Point2<T>>,
+    pub closed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContourBand<T: Float> {
+    pub lower: T,
+    pub upper: T,
+    pub polygons: Vec<Polygon<T>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Node {
+    Vertex(usize),
+    Crossing(usize, usize, usize),
+}
+
+fn walk(next: &mut BTreeMap<Node, Node>, start: Node) -> Vec<Node> {
+    let mut nodes = vec![start];
+    let mut current = start;
+    while let Some(node) = next.remove(&current) {
+        nodes.push(node);
+        current = node;
+    }
+    nodes
+}
+
+struct Field<T: Float> {
+    points: Vec<Point2<T>>,
+    values: Vec<T>,
+    triangles: Vec<[usize; 3]>,
+    levels: Vec<T>,
+}
+
+impl<T: Float> Field<T> {
+    fn new(
+        triangulation: &Triangulation2<T>,
+        samples: impl IntoIterator<Item = (Point2<T>, T)>,
+        levels: &[T],
+    ) -> Option<Self> {
+        let VertexValues {
+            points,
+            values,
+            mut triangles,
+        } = VertexValues::new(&triangulation.triangles, samples)?;
+        for (ids, t) in triangles.iter_mut().zip(&triangulation.triangles) {
+            if t.signed_area() < T::zero() {
+                ids.swap(1, 2);
+            }
+        }
+        Some(Self {
+            points,
+            values,
+            triangles,
+            levels: levels.to_vec(),
+        })
+    }
+
+    const fn crossing(i: usize, j: usize, level: usize) -> Node {
+        if i < j {
+            Node::Crossing(i, j, level)
+        } else {
+            Node::Crossing(j, i, level)
+        }
+    }
+
+    fn position(&self, node: Node) -> Point2<T> {
+        match node {
+            Node::Vertex(i) => self.points[i],
+            Node::Crossing(i, j, level) => {
+                let (vi, vj) = (self.values[i], self.values[j]);
+                let t = (self.levels[level] - vi) / (vj - vi);
+                self.points[i].lerp(self.points[j], t)
+            }
+        }
+    }
+
+    fn positions(&self, nodes: &[Node]) -> Vec<Point2<T>> {
+        let mut points = nodes
+            .iter()
+            .map(|&node| self.position(node))
+            .collect::<Vec<_>>();
+        points.dedup();
+        points
+    }
+
+    fn isolines(&self, level: usize) -> Vec<Isoline<T>> {
+        let value = self.levels[level];
+        let mut next = BTreeMap::new();
+        for &ids in &self.triangles {
+            let above = ids.map(|i| self.values[i] >= value);
+            let count = above.iter().filter(|&&a| a).count();
+            if count == 0 || count == 3 {
+                continue;
+            }
+            let Some(odd) = (0..3).find(|&k| above[k] == (count == 1)) else {
+                continue;
+            };
+            let (o, n, p) = (ids[odd], ids[(odd + 1) % 3], ids[(odd + 2) % 3]);
+            let (from, to) = (Self::crossing(o, n, level), Self::crossing(p, o, level));
+            if count == 1 {
+                next.insert(from, to);
+            } else {
+                next.insert(to, from);
+            }
+        }
+
+        let targets = next.values().copied().collect::<BTreeSet<_>>();
+        let starts = next
+            .keys()
+            .copied()
+            .filter(|node| !targets.contains(node))
+            .collect::<Vec<_>>();
+        let mut isolines = starts
+            .into_iter()
+            .map(|start| Isoline {
+                level: value,
+                points: self.positions(&walk(&mut next, start)),
+                closed: false,
+            })
+            .collect::<Vec<_>>();
+        while let Some((&start, _)) = next.first_key_value() {
+            let mut nodes = walk(&mut next, start);
+            nodes.pop();
+            let mut points = self.positions(&nodes);
+            if points.len() > 1 && points.first() == points.last() {
+                points.pop();
+            }
+            isolines.push(Isoline {
+                level: value,
+                points,
+                closed: true,
+            });
+        }
+        isolines
+    }
+
+    fn band_piece(&self, ids: [usize; 3], lower: usize, upper: usize) -> Vec<Node> {
+        let (lo, hi) = (self.levels[lower], self.levels[upper]);
+        let mut nodes = Vec::new();
+        for k in 0..3 {
+            let (i, j) = (ids[k], ids[(k + 1) % 3]);
+            let (vi, vj) = (self.values[i], self.values[j]);
+            if vi >= lo && vi <= hi {
+                nodes.push(Node::Vertex(i));
+            }
+            let mut crossings = [lower, upper]
+                .into_iter()
+                .filter(|&level| {
+                    let value = self.levels[level];
+                    (vi < value && value < vj) || (vj < value && value < vi)
+                })
+                .map(|level| ((self.levels[level] - vi) / (vj - vi), level))
+                .collect::<Vec<_>>();
+            crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+            nodes.extend(
+                crossings
+                    .into_iter()
+                    .map(|(_, level)| Self::crossing(i, j, level)),
+            );
+        }
+        nodes
+    }
+
+    fn band(&self, lower: usize, upper: usize) -> ContourBand<T> {
+        let mut edges = BTreeSet::new();
+        for &ids in &self.triangles {
+            let nodes = self.band_piece(ids, lower, upper);
+            if nodes.len() < 3 {
+                continue;
+            }
+            for k in 0..nodes.len() {
+                let (a, b) = (nodes[k], nodes[(k + 1) % nodes.len()]);
+                if !edges.remove(&(b, a)) {
+                    edges.insert((a, b));
+                }
+            }
+        }
+
+        let mut outgoing: BTreeMap<Node, Vec<Node>> = BTreeMap::new();
+        for (a, b) in edges {
+            outgoing.entry(a).or_default().push(b);
+        }
+        let mut exteriors = Vec::new();
+        let mut holes = Vec::new();
+        while let Some((&start, _)) = outgoing.first_key_value() {
+            let mut ring = vec![start];
+            let mut current = start;
+            while let Some(next) = outgoing.get_mut(&current).and_then(Vec::pop) {
+                if outgoing.get(&current).is_some_and(Vec::is_empty) {
+                    outgoing.remove(&current);
+                }
+                if next == start {
+                    break;
+                }
+                ring.push(next);
+                current = next;
+            }
+            let ring = self.positions(&ring);
+            if ring.len() < 3 {
+                continue;
+            }
+            let area = ring_signed_area(&ring);
+            if area > T::zero() {
+                exteriors.push((area, Polygon::new(ring, Vec::new())));
+            } else if area < T::zero() {
+                holes.push(ring);
+            }
+        }
+
+        for hole in holes {
+            let container = exteriors
+                .iter_mut()
+                .filter(|(_, polygon)| {
+                    hole.iter()
+                        .all(|&p| locate_in_ring(&polygon.exterior, p) != Location::Outside)
+                })
+                .min_by(|a, b| a.0.total_cmp(&b.0));
+            if let Some((_, polygon)) = container {
+                polygon.holes.push(hole);
+            }
+        }
+
+        ContourBand {
+            lower: self.levels[lower],
+            upper: self.levels[upper],
+            polygons: exteriors.into_iter().map(|(_, polygon)| polygon).collect(),
+        }
+    }
+}
+
+fn sorted_levels<T: Float>(levels: &[T]) -> Vec<T> {
+    let mut levels = levels.to_vec();
+    levels.sort_by(T::total_cmp);
+    levels.dedup();
+    levels
+}
+
+impl<T: Float> Triangulation2<T> {
+    pub fn isolines(
+        &self,
+        samples: impl IntoIterator<Item = (Point2<T>, T)>,
+        levels: &[T],
+    ) -> Option<Vec<Isoline<T>>> {
+        let field = Field::new(self, samples, levels)?;
+        Some(
+            (0..levels.len())
+                .flat_map(|level| field.isolines(level))
+                .collect(),
+        )
+    }
+
+    pub fn contour_bands(
+        &self,
+        samples: impl IntoIterator<Item = (Point2<T>, T)>,
+        levels: &[T],
+    ) -> Option<Vec<ContourBand<T>>> {
+        let field = Field::new(self, samples, &sorted_levels(levels))?;
+        Some(
+            (1..field.levels.len())
+                .map(|upper| field.band(upper - 1, upper))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::triangulate;
+
+    fn grid(f: impl Fn(Point2<f64>) -> f64) -> (Triangulation2<f64>, Vec<(Point2<f64>, f64)>) {
+        let coordinate = |i: u8| <f64 as From<u8>>::from(i);
+        let points = (0..=4)
+            .flat_map(|i| (0..=4).map(move |j| Point2::from([coordinate(i), coordinate(j)])))
+            .collect::<Vec<_>>();
+        let samples = points.iter().map(|&p| (p, f(p))).collect();
+        (triangulate(points).unwrap(), samples)
+    }
+
+    fn cone(p: Point2<f64>) -> f64 {
+        p.distance(Point2::from([2.0, 2.0]))
+    }
+
+    fn total_area(band: &ContourBand<f64>) -> f64 {
+        band.polygons.iter().map(Polygon::area).sum()
+    }
+
+    #[test]
+    fn test_open_isoline() {
+        let (triangulation, samples) = grid(|p| p.x);
+        let isolines = triangulation.isolines(samples, &[1.5]).unwrap();
+        assert_eq!(isolines.len(), 1);
+        let isoline = &isolines[0];
+        assert!(!isoline.closed);
+        assert!(isoline.points.iter().all(|p| (p.x - 1.5).abs() < 1e-12));
+        assert_eq!(isoline.points.first().unwrap().y, 4.0);
+        assert_eq!(isoline.points.last().unwrap().y, 0.0);
+    }
+
+    #[test]
+    fn test_closed_isoline() {
+        let (triangulation, samples) = grid(cone);
+        let isolines = triangulation.isolines(samples, &[1.5, 10.0]).unwrap();
+        assert_eq!(isolines.len(), 1);
+        let isoline = &isolines[0];
+        assert!(isoline.closed);
+        assert!(ring_signed_area(&isoline.points) < 0.0);
+        assert_eq!(
+            locate_in_ring(&isoline.points, Point2::from([2.0, 2.0])),
+            Location::Inside
+        );
+        assert!(isoline.points.iter().all(|p| (cone(*p) - 1.5).abs() < 0.3));
+    }
+
+    #[test]
+    fn test_contour_bands() {
+        let (triangulation, samples) = grid(|p| p.x);
+        let bands = triangulation
+            .contour_bands(samples, &[4.0, 0.0, 1.5])
+            .unwrap();
+        assert_eq!(bands.len(), 2);
+        assert_eq!((bands[0].lower, bands[0].upper), (0.0, 1.5));
+        assert_eq!(bands[0].polygons.len(), 1);
+        assert!(bands[0].polygons[0].holes.is_empty());
+        assert!((total_area(&bands[0]) - 6.0).abs() < 1e-12);
+        assert!((total_area(&bands[1]) - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_contour_band_with_hole() {
+        let (triangulation, samples) = grid(cone);
+        let bands = triangulation
+            .contour_bands(samples, &[0.0, 1.5, 3.0])
+            .unwrap();
+        assert_eq!(bands[0].polygons.len(), 1);
+        assert!(bands[0].polygons[0].holes.is_empty());
+        assert_eq!(bands[1].polygons.len(), 1);
+        assert_eq!(bands[1].polygons[0].holes.len(), 1);
+        assert!((total_area(&bands[0]) + total_area(&bands[1]) - 16.0).abs() < 1e-9);
+        assert!(bands[1].polygons[0].contains(Point2::from([2.0, 0.5])));
+        assert!(!bands[1].polygons[0].contains(Point2::from([2.0, 2.0])));
+    }
+
+    #[test]
+    fn test_missing_values() {
+        let (triangulation, samples) = grid(cone);
+        assert!(
+            triangulation
+                .isolines(samples.into_iter().skip(3), &[1.0])
+                .is_none()
+        );
+    }
+}
@@ -1,78 +1,256 @@
-use geomutil_util::{Point2, Triangle};
-use std::collections::HashMap;
+use std::collections::BTreeSet;
 
-pub struct Triangulation2 {
-    pub bounding_triangle: Triangle,
-    pub triangles: Vec<Triangle>,
+use geomutil_util::{Edge2, Float, Point2, RTree, Triangle};
+
+#[cfg(feature = "rayon")]
+use crate::parallel::parallel_sweep_hull;
+use crate::{
+    flip::{counter_clockwise, in_circle},
+    sweep_hull::sweep_hull,
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriangulationAlgorithm {
+    #[default]
+    BowyerWatson,
+    SweepHull,
+    /// Runs sweep-hull on strips in parallel. Without the `rayon` feature this falls back to
+    /// [`TriangulationAlgorithm::SweepHull`].
+    Parallel,
+}
+
+/// The coordinate bound for [`triangulate_with`]: [`Float`], plus `Send + Sync` when the
+/// `rayon` feature is enabled.
+#[cfg(feature = "rayon")]
+pub trait TriangulationFloat: Float + Send + Sync {}
+
+#[cfg(feature = "rayon")]
+impl<T: Float + Send + Sync> TriangulationFloat for T {}
+
+/// The coordinate bound for [`triangulate_with`]: [`Float`], plus `Send + Sync` when the
+/// `rayon` feature is enabled.
+#[cfg(not(feature = "rayon"))]
+pub trait TriangulationFloat: Float {}
+
+#[cfg(not(feature = "rayon"))]
+impl<T: Float> TriangulationFloat for T {}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TriangulationOptions {
+    pub algorithm: TriangulationAlgorithm,
+}
+
+impl TriangulationOptions {
+    #[must_use]
+    pub const fn new(algorithm: TriangulationAlgorithm) -> Self {
+        Self { algorithm }
+    }
 }
 
-impl Triangulation2 {
-    fn new(bounding_triangle: Triangle) -> Self {
+pub struct Triangulation2<T: Float> {
+    pub bounding_triangle: Triangle<T>,
+    pub triangles: Vec<Triangle<T>>,
+}
+
+pub fn boundary_edges<'a, T: Float + 'a>(
+    triangles: impl IntoIterator<Item = &'a Triangle<T>>,
+) -> Vec<Edge2<T>> {
+    let mut edges = triangles
+        .into_iter()
+        .flat_map(|t| t.edges().map(|e| e.canonical()))
+        .collect::<Vec<_>>();
+    edges.sort_by(Edge2::total_cmp);
+    edges
+        .chunk_by(|a, b| a == b)
+        .filter(|chunk| chunk.len() == 1)
+        .map(|chunk| chunk[0])
+        .collect()
+}
+
+impl<T: Float> Triangulation2<T> {
+    #[must_use]
+    pub fn triangle_index(&self) -> RTree<2, T, usize> {
+        self.triangles
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (t.bounding_box(), i))
+            .collect()
+    }
+
+    pub(crate) fn new(bounding_triangle: Triangle<T>) -> Self {
         Self {
             bounding_triangle: bounding_triangle.clone(),
             triangles: vec![bounding_triangle],
         }
     }
 
-    fn add(&mut self, point: Point2) {
-        let mut triangles_to_keep = Vec::with_capacity(self.triangles.len());
-        let mut triangles_to_add_edges = HashMap::with_capacity(self.triangles.len() * 2);
-        for t in self.triangles.drain(..) {
-            if t.is_inside_circumcircle(point) {
-                for e in t.edges() {
-                    let canonical_edge = e.canonical();
-                    *triangles_to_add_edges.entry(canonical_edge).or_insert(0) += 1;
+    pub(crate) fn add(&mut self, point: Point2<T>) {
+        let (bad, good): (Vec<_>, Vec<_>) = self
+            .triangles
+            .drain(..)
+            .partition(|t| t.is_inside_circumcircle(point));
+        self.triangles = good;
+        let new_triangles = boundary_edges(&bad)
+            .into_iter()
+            .map(|e| Triangle::new(e.a, e.b, point));
+        self.triangles.extend(new_triangles);
+    }
+
+    pub(crate) fn touches_bounding_triangle(&self, t: &Triangle<T>) -> bool {
+        t.has_point(&self.bounding_triangle.a)
+            || t.has_point(&self.bounding_triangle.b)
+            || t.has_point(&self.bounding_triangle.c)
+    }
+
+    fn finalize(&mut self, points: &[Point2<T>]) {
+        let triangles = std::mem::take(&mut self.triangles);
+        self.triangles = triangles
+            .into_iter()
+            .filter(|t| !self.touches_bounding_triangle(t))
+            .collect();
+        self.close_hull(points);
+    }
+
+    pub(crate) fn close_hull(&mut self, points: &[Point2<T>]) {
+        let index = |p: Point2<T>| points.binary_search_by(|q| q.total_cmp(&p)).ok();
+        let mut closed = BTreeSet::new();
+        for t in &self.triangles {
+            if let [Some(a), Some(b), Some(c)] = counter_clockwise(t).map(index) {
+                closed.extend([(a, b), (b, c), (c, a)]);
+            }
+        }
+        let mut open = closed
+            .iter()
+            .map(|&(a, b)| (b, a))
+            .filter(|e| !closed.contains(e))
+            .collect::<Vec<_>>();
+        if closed.is_empty() {
+            open.extend(hull_edge(points));
+        }
+        let mut candidates = open
+            .iter()
+            .flat_map(|&(a, b)| [a, b])
+            .collect::<BTreeSet<_>>();
+        let used = closed.iter().map(|&(a, _)| a).collect::<BTreeSet<_>>();
+        candidates.extend((0..points.len()).filter(|i| !used.contains(i)));
+        while let Some((a, b)) = open.pop() {
+            if closed.contains(&(a, b)) {
+                continue;
+            }
+            let (pa, pb) = (points[a], points[b]);
+            let mut apex = None;
+            for &i in &candidates {
+                let p = points[i];
+                if (pb - pa).cross(p - pa) > T::zero()
+                    && apex.is_none_or(|c: usize| in_circle(pa, pb, points[c], p))
+                {
+                    apex = Some(i);
                 }
-            } else {
-                triangles_to_keep.push(t);
             }
+            let Some(c) = apex else {
+                continue;
+            };
+            self.triangles.push(Triangle::new(pa, pb, points[c]));
+            closed.extend([(a, b), (b, c), (c, a)]);
+            open.extend([(c, b), (a, c)]);
         }
-        self.triangles = triangles_to_keep;
-        let new_triangles = triangles_to_add_edges
-            .into_iter()
-            .filter(|(_, c)| c.eq(&1))
-            .filter_map(|(e, _)| Triangle::new(e.a, e.b, point));
-        self.triangles.extend(new_triangles);
     }
+}
 
-    fn finalize(&mut self) {
-        self.triangles.retain(|t| {
-            !(t.has_point(&self.bounding_triangle.a)
-                || t.has_point(&self.bounding_triangle.b)
-                || t.has_point(&self.bounding_triangle.c))
-        });
+fn hull_edge<T: Float>(points: &[Point2<T>]) -> Option<(usize, usize)> {
+    let a = 0;
+    let mut b = 1;
+    for (i, &p) in points.iter().enumerate().skip(2) {
+        let side = (points[b] - points[a]).cross(p - points[a]);
+        if side < T::zero()
+            || (side == T::zero() && points[a].distance(p) < points[a].distance(points[b]))
+        {
+            b = i;
+        }
     }
+    (points.len() > 2).then_some((a, b))
 }
 
-fn get_bounding_triangle(points: impl IntoIterator<Item = Point2>) -> Option<Triangle> {
+pub(crate) fn get_bounding_triangle<T: Float>(
+    points: impl IntoIterator<Item = Point2<T>>,
+) -> Option<Triangle<T>> {
     let bbox = Point2::bounding_box(points)?;
     let d = bbox.dimensions();
-    let d = 3.0 * d.x.max(d.y);
     let center = bbox.center();
-    Triangle::new(
-        Point2::from([0.866f32.mul_add(-d, center.x), 0.5f32.mul_add(-d, center.y)]),
-        Point2::from([0.866f32.mul_add(d, center.x), 0.5f32.mul_add(-d, center.y)]),
+    let size = d.x.max(d.y);
+    let size = if size > T::zero() {
+        size
+    } else {
+        T::one().max(center.x.abs()).max(center.y.abs())
+    };
+    let d = T::from(3.0) * size;
+    let (sin, cos) = (T::from(0.5), T::from(0.866));
+    Some(Triangle::new(
+        Point2::from([center.x - cos * d, center.y - sin * d]),
+        Point2::from([center.x + cos * d, center.y - sin * d]),
         Point2::from([center.x, center.y + d]),
-    )
+    ))
+}
+
+fn bowyer_watson<T: Float>(
+    points: &[Point2<T>],
+    bounding_triangle: Triangle<T>,
+) -> Triangulation2<T> {
+    let mut triangulation = Triangulation2::new(bounding_triangle);
+    for &point in points {
+        triangulation.add(point);
+    }
+    triangulation.finalize(points);
+    triangulation
 }
 
-pub fn triangulate(points: impl IntoIterator<Item = Point2>) -> Option<Triangulation2> {
+pub fn triangulate<T: Float>(
+    points: impl IntoIterator<Item = Point2<T>>,
+) -> Option<Triangulation2<T>> {
     let points = Point2::unique(points);
     if points.len() < 3 {
         return None;
     }
-    let bounding_triangle = get_bounding_triangle(points.clone())?;
-    let mut triangulation = Triangulation2::new(bounding_triangle);
-    for point in points {
-        triangulation.add(point);
+    let bounding_triangle = get_bounding_triangle(points.iter().copied())?;
+    Some(bowyer_watson(&points, bounding_triangle))
+}
+
+pub fn triangulate_with<T: TriangulationFloat>(
+    points: impl IntoIterator<Item = Point2<T>>,
+    options: TriangulationOptions,
+) -> Option<Triangulation2<T>> {
+    let points = Point2::unique(points);
+    if points.len() < 3 {
+        return None;
     }
-    triangulation.finalize();
-    Some(triangulation)
+    let bounding_triangle = get_bounding_triangle(points.iter().copied())?;
+    let triangles = match options.algorithm {
+        TriangulationAlgorithm::BowyerWatson => {
+            return Some(bowyer_watson(&points, bounding_triangle));
+        }
+        TriangulationAlgorithm::SweepHull => sweep_hull(&points),
+        #[cfg(feature = "rayon")]
+        TriangulationAlgorithm::Parallel => {
+            parallel_sweep_hull(&points, 4 * rayon::current_num_threads())
+        }
+        #[cfg(not(feature = "rayon"))]
+        TriangulationAlgorithm::Parallel => sweep_hull(&points),
+    };
+    Some(Triangulation2 {
+        bounding_triangle,
+        triangles: triangles
+            .into_iter()
+            .map(|[a, b, c]| Triangle::new(points[a], points[b], points[c]))
+            .collect(),
+    })
 }
 
 #[cfg(test)]
 mod tests {
+    use geomutil_util::BoundingBox2;
+
     use super::*;
+    use crate::test_utils::random_points;
 
     #[test]
     fn test_triangulate_rectangle() {
@@ -112,13 +290,7 @@ mod tests {
         let t2 = &triangulation.triangles[1];
 
         // Check if the two triangles cover all original points
-        let mut all_points_in_triangulation = std::collections::HashSet::new();
-        all_points_in_triangulation.insert(t1.a);
-        all_points_in_triangulation.insert(t1.b);
-        all_points_in_triangulation.insert(t1.c);
-        all_points_in_triangulation.insert(t2.a);
-        all_points_in_triangulation.insert(t2.b);
-        all_points_in_triangulation.insert(t2.c);
+        let all_points_in_triangulation = Point2::unique([t1.a, t1.b, t1.c, t2.a, t2.b, t2.c]);
 
         assert!(all_points_in_triangulation.contains(&p1));
         assert!(all_points_in_triangulation.contains(&p2));
@@ -126,18 +298,22 @@ mod tests {
         assert!(all_points_in_triangulation.contains(&p4));
 
         // Check that the two triangles share a common edge (the diagonal)
-        let mut all_edges = HashMap::new();
-        for t in &triangulation.triangles {
-            for edge in t.edges().iter().map(|e| e.canonical()) {
-                *all_edges.entry(edge).or_insert(0) += 1;
-            }
-        }
+        let mut all_edges = triangulation
+            .triangles
+            .iter()
+            .flat_map(|t| t.edges().map(|e| e.canonical()))
+            .collect::<Vec<_>>();
+        all_edges.sort_by(Edge2::total_cmp);
+        let counts = all_edges
+            .chunk_by(|a, b| a == b)
+            .map(<[_]>::len)
+            .collect::<Vec<_>>();
 
         // In a valid triangulation of a convex polygon, internal edges appear twice,
         // and boundary edges appear once. For a rectangle split into two triangles,
         // there should be 4 boundary edges and 1 internal (shared) edge.
-        let boundary_edges_count = all_edges.values().filter(|&&c| c == 1).count();
-        let internal_edges_count = all_edges.values().filter(|&&c| c == 2).count();
+        let boundary_edges_count = counts.iter().filter(|&&c| c == 1).count();
+        let internal_edges_count = counts.iter().filter(|&&c| c == 2).count();
 
         assert_eq!(boundary_edges_count, 4, "Expected 4 boundary edges");
         assert_eq!(internal_edges_count, 1, "Expected 1 internal (shared) edge");
@@ -147,4 +323,126 @@ mod tests {
             triangulation.triangles
         );
     }
+
+    #[test]
+    fn test_triangle_index() {
+        let coordinate = |i: u8| <f64 as From<u8>>::from(i);
+        let points = (0..10).flat_map(|i| {
+            (0..10).map(move |j| {
+                Point2::from([coordinate(i), 0.1f64.mul_add(coordinate(i), coordinate(j))])
+            })
+        });
+        let triangulation = triangulate(points).unwrap();
+        let index = triangulation.triangle_index();
+        assert_eq!(index.len(), triangulation.triangles.len());
+
+        let p = Point2::from([4.3, 5.6]);
+        let query = BoundingBox2::new(p, p);
+        let containing = index
+            .intersecting(&query)
+            .map(|(_, &i)| i)
+            .filter(|&i| triangulation.triangles[i].contains(p))
+            .collect::<Vec<_>>();
+        assert_eq!(containing.len(), 1);
+
+        let (&nearest, distance) = index
+            .nearest(Point2::from([20.0, 5.0]), |_, &i| {
+                triangulation.triangles[i].distance_to_point(Point2::from([20.0, 5.0]))
+            })
+            .unwrap();
+        assert!(triangulation.triangles[nearest].has_point(&Point2::from([9.0, 5.9])));
+        assert!((distance - 11.0).abs() < 0.5);
+    }
+
+    fn canonical(triangulation: &Triangulation2<f64>) -> Vec<[Point2<f64>; 3]> {
+        let mut triangles = triangulation
+            .triangles
+            .iter()
+            .map(|t| {
+                let mut vertices = [t.a, t.b, t.c];
+                vertices.sort_by(|a, b| a.total_cmp(b));
+                vertices
+            })
+            .collect::<Vec<_>>();
+        triangles.sort_by(|a, b| {
+            a.iter()
+                .zip(b)
+                .map(|(p, q)| p.total_cmp(q))
+                .find(|o| o.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        triangles
+    }
+
+    fn hull_size(points: &[Point2<f64>]) -> usize {
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let mut hull: Vec<Point2<f64>> = Vec::new();
+        for chain in [sorted.clone(), sorted.into_iter().rev().collect()] {
+            let start = hull.len();
+            for p in chain {
+                while hull.len() >= start + 2 {
+                    let (o, a) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+                    if (a - o).cross(p - o) > 0.0 {
+                        break;
+                    }
+                    hull.pop();
+                }
+                hull.push(p);
+            }
+            hull.pop();
+        }
+        hull.len()
+    }
+
+    fn sweep_hull(points: impl IntoIterator<Item = Point2<f64>>) -> Triangulation2<f64> {
+        triangulate_with(
+            points,
+            TriangulationOptions::new(TriangulationAlgorithm::SweepHull),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sweep_hull_matches_bowyer_watson() {
+        for (n, seed) in [(300, 1), (300, 2), (500, 3), (500, 4)] {
+            let points = random_points(n, seed);
+            let bowyer_watson = triangulate(points.iter().copied()).unwrap();
+            assert_eq!(
+                bowyer_watson.triangles.len(),
+                2 * n - 2 - hull_size(&points)
+            );
+            assert_eq!(canonical(&sweep_hull(points)), canonical(&bowyer_watson));
+        }
+    }
+
+    #[test]
+    fn test_sweep_hull_degenerate_inputs() {
+        let coordinate = |i: u8| <f64 as From<u8>>::from(i);
+        let grid = (0..10)
+            .flat_map(|i| (0..10).map(move |j| Point2::from([coordinate(i), coordinate(j)])))
+            .collect::<Vec<_>>();
+        let sweep = sweep_hull(grid.iter().copied().chain(grid.iter().copied()));
+        assert_eq!(sweep.triangles.len(), 162);
+        let area: f64 = sweep.triangles.iter().map(Triangle::signed_area).sum();
+        assert!((area - 81.0).abs() < 1e-9);
+
+        let rectangle = [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]].map(Point2::from);
+        assert_eq!(sweep_hull(rectangle).triangles.len(), 2);
+
+        let collinear = (0..5).map(|i| Point2::from([coordinate(i), coordinate(i)]));
+        assert!(sweep_hull(collinear.clone()).triangles.is_empty());
+        assert!(triangulate(collinear).unwrap().triangles.is_empty());
+    }
+
+    #[test]
+    fn test_parallel_matches_sweep_hull() {
+        let points = random_points(20_000, 7);
+        let parallel = triangulate_with(
+            points.iter().copied(),
+            TriangulationOptions::new(TriangulationAlgorithm::Parallel),
+        )
+        .unwrap();
+        assert_eq!(canonical(&parallel), canonical(&sweep_hull(points)));
+    }
 }
@@ -0,0 +1,577 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use geomutil_util::{Float, Point2, Triangle};
+
+use crate::{
+    Triangulation2, delaunay2d::get_bounding_triangle, flip::in_circle, sweep_hull::sweep_hull,
+};
+
+const GHOST: usize = usize::MAX;
+
+struct Key<T: Float>(Point2<T>);
+
+impl<T: Float> PartialEq for Key<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<T: Float> Eq for Key<T> {}
+
+impl<T: Float> PartialOrd for Key<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for Key<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+pub struct DynamicTriangulation2<T: Float> {
+    vertices: Vec<Point2<T>>,
+    index: BTreeMap<Key<T>, usize>,
+    free_vertices: Vec<usize>,
+    vertex_triangle: Vec<usize>,
+    triangles: Vec<[usize; 3]>,
+    neighbours: Vec<[usize; 3]>,
+    free_triangles: Vec<usize>,
+    finite: usize,
+    hint: usize,
+}
+
+fn edge(t: [usize; 3], k: usize) -> (usize, usize) {
+    (t[k], t[(k + 1) % 3])
+}
+
+fn normalized([a, b, c]: [usize; 3]) -> [usize; 3] {
+    match (a, b) {
+        (GHOST, _) => [b, c, a],
+        (_, GHOST) => [c, a, b],
+        _ => [a, b, c],
+    }
+}
+
+impl<T: Float> Default for DynamicTriangulation2<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float> FromIterator<Point2<T>> for DynamicTriangulation2<T> {
+    fn from_iter<I: IntoIterator<Item = Point2<T>>>(iter: I) -> Self {
+        let mut triangulation = Self::new();
+        for p in iter {
+            if !triangulation.contains(p) {
+                triangulation.add_vertex(p);
+            }
+        }
+        triangulation.rebuild();
+        triangulation
+    }
+}
+
+impl<T: Float> DynamicTriangulation2<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            vertices: Vec::new(),
+            index: BTreeMap::new(),
+            free_vertices: Vec::new(),
+            vertex_triangle: Vec::new(),
+            triangles: Vec::new(),
+            neighbours: Vec::new(),
+            free_triangles: Vec::new(),
+            finite: 0,
+            hint: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point2<T>> + '_ {
+        self.index.keys().map(|key| key.0)
+    }
+
+    #[must_use]
+    pub fn contains(&self, p: Point2<T>) -> bool {
+        self.index.contains_key(&Key(p))
+    }
+
+    fn add_vertex(&mut self, p: Point2<T>) -> usize {
+        let v = if let Some(v) = self.free_vertices.pop() {
+            self.vertices[v] = p;
+            v
+        } else {
+            self.vertices.push(p);
+            self.vertex_triangle.push(GHOST);
+            self.vertices.len() - 1
+        };
+        self.index.insert(Key(p), v);
+        v
+    }
+
+    fn clear_mesh(&mut self) {
+        self.triangles.clear();
+        self.neighbours.clear();
+        self.free_triangles.clear();
+        self.finite = 0;
+        self.hint = 0;
+    }
+
+    fn rebuild(&mut self) {
+        self.clear_mesh();
+        let ids = self.index.values().copied().collect::<Vec<_>>();
+        let points = ids.iter().map(|&v| self.vertices[v]).collect::<Vec<_>>();
+        let mut triangles = sweep_hull(&points)
+            .into_iter()
+            .map(|t| t.map(|i| ids[i]))
+            .collect::<Vec<_>>();
+        let mut edges = triangles
+            .iter()
+            .flat_map(|&t| (0..3).map(move |k| edge(t, k)))
+            .collect::<Vec<_>>();
+        edges.sort_unstable();
+        let ghosts = edges
+            .iter()
+            .filter(|&&(a, b)| edges.binary_search(&(b, a)).is_err())
+            .map(|&(a, b)| [b, a, GHOST])
+            .collect::<Vec<_>>();
+        triangles.extend(ghosts);
+        self.retriangulate(&[], &[], triangles);
+    }
+
+    fn allocate(&mut self, t: [usize; 3]) -> usize {
+        if t[2] != GHOST {
+            self.finite += 1;
+        }
+        let slot = self.free_triangles.pop().unwrap_or(self.triangles.len());
+        if slot == self.triangles.len() {
+            self.triangles.push(t);
+            self.neighbours.push([GHOST; 3]);
+        } else {
+            self.triangles[slot] = t;
+        }
+        for v in t.into_iter().filter(|&v| v != GHOST) {
+            self.vertex_triangle[v] = slot;
+        }
+        slot
+    }
+
+    fn boundary(&self, cavity: &[usize]) -> Vec<(usize, usize, usize)> {
+        let mut boundary = Vec::new();
+        for &t in cavity {
+            for k in 0..3 {
+                let n = self.neighbours[t][k];
+                if !cavity.contains(&n) {
+                    let (a, b) = edge(self.triangles[t], k);
+                    boundary.push((a, b, n));
+                }
+            }
+        }
+        boundary
+    }
+
+    fn retriangulate(
+        &mut self,
+        cavity: &[usize],
+        boundary: &[(usize, usize, usize)],
+        created: Vec<[usize; 3]>,
+    ) {
+        for &t in cavity {
+            if self.triangles[t][2] != GHOST {
+                self.finite -= 1;
+            }
+            self.triangles[t] = [GHOST; 3];
+            self.free_triangles.push(t);
+        }
+        let mut edges = Vec::with_capacity(3 * created.len());
+        for t in created {
+            let slot = self.allocate(t);
+            edges.extend((0..3).map(|k| (edge(t, k), slot, k)));
+        }
+        edges.sort_unstable();
+        for &((a, b), t, k) in &edges {
+            if let Some(&(_, _, n)) = boundary.iter().find(|e| (e.0, e.1) == (a, b)) {
+                let j = (0..3)
+                    .find(|&j| edge(self.triangles[n], j) == (b, a))
+                    .unwrap_or_default();
+                self.neighbours[t][k] = n;
+                self.neighbours[n][j] = t;
+            } else if let Ok(i) = edges.binary_search_by(|e| e.0.cmp(&(b, a))) {
+                self.neighbours[t][k] = edges[i].1;
+            }
+        }
+        if let Some(&(_, t, _)) = edges.first() {
+            self.hint = t;
+        }
+    }
+
+    fn conflicts(&self, t: usize, p: Point2<T>) -> bool {
+        let [a, b, c] = self.triangles[t];
+        let (pa, pb) = (self.vertices[a], self.vertices[b]);
+        if c == GHOST {
+            let side = (pb - pa).cross(p - pa);
+            side > T::zero() || (side == T::zero() && (pa - p).dot(pb - p) < T::zero())
+        } else {
+            in_circle(pa, pb, self.vertices[c], p)
+        }
+    }
+
+    fn locate(&self, p: Point2<T>) -> usize {
+        let mut t = self.hint;
+        if self.triangles[t][2] == GHOST {
+            t = self.neighbours[t][0];
+        }
+        'walk: loop {
+            let vertices = self.triangles[t];
+            if vertices[2] == GHOST {
+                return t;
+            }
+            for k in 0..3 {
+                let (a, b) = edge(vertices, k);
+                let (pa, pb) = (self.vertices[a], self.vertices[b]);
+                if (pb - pa).cross(p - pa) < T::zero() {
+                    t = self.neighbours[t][k];
+                    continue 'walk;
+                }
+            }
+            return t;
+        }
+    }
+
+    fn star(&self, v: usize) -> Vec<usize> {
+        let start = self.vertex_triangle[v];
+        let mut star = vec![start];
+        let mut t = start;
+        loop {
+            let k = (0..3)
+                .find(|&k| self.triangles[t][k] == v)
+                .unwrap_or_default();
+            t = self.neighbours[t][(k + 2) % 3];
+            if t == start {
+                return star;
+            }
+            star.push(t);
+        }
+    }
+
+    fn fill_hole(&self, mut polygon: Vec<usize>) -> Vec<[usize; 3]> {
+        let ghost = polygon.iter().position(|&v| v == GHOST);
+        if let Some(i) = ghost {
+            polygon.rotate_left(i + 1);
+        }
+        let mut triangles = Vec::with_capacity(polygon.len().saturating_sub(2));
+        while polygon.len() > 3 {
+            let n = polygon.len();
+            let finite = if ghost.is_some() { n - 1 } else { n };
+            let ear = |i: usize| {
+                [polygon[(i + n - 1) % n], polygon[i], polygon[(i + 1) % n]]
+                    .map(|v| self.vertices[v])
+            };
+            let convexity = |i: usize| {
+                let [a, b, c] = ear(i);
+                (b - a).cross(c - a)
+            };
+            let candidates = if ghost.is_some() { 1..finite - 1 } else { 0..n };
+            let delaunay = candidates.clone().find(|&i| {
+                let [a, b, c] = ear(i);
+                convexity(i) > T::zero()
+                    && polygon[..finite].iter().all(|&d| {
+                        let d = self.vertices[d];
+                        d == a || d == b || d == c || !in_circle(a, b, c, d)
+                    })
+            });
+            let fallback = || {
+                ghost
+                    .is_none()
+                    .then(|| candidates.max_by(|&i, &j| convexity(i).total_cmp(&convexity(j))))
+                    .flatten()
+            };
+            let Some(i) = delaunay.or_else(fallback) else {
+                break;
+            };
+            triangles.push([polygon[(i + n - 1) % n], polygon[i], polygon[(i + 1) % n]]);
+            polygon.remove(i);
+        }
+        if ghost.is_some() {
+            let chain = &polygon[..polygon.len() - 1];
+            triangles.extend(chain.windows(2).map(|w| [w[0], w[1], GHOST]));
+        } else if let [a, b, c] = polygon[..] {
+            triangles.push([a, b, c]);
+        }
+        triangles
+    }
+
+    pub fn insert(&mut self, p: Point2<T>) -> bool {
+        if self.contains(p) {
+            return false;
+        }
+        if self.finite == 0 {
+            let collinear = match (self.index.first_key_value(), self.index.last_key_value()) {
+                (Some((a, _)), Some((b, _))) => (b.0 - a.0).cross(p - a.0) == T::zero(),
+                _ => true,
+            };
+            self.add_vertex(p);
+            if !collinear {
+                self.rebuild();
+            }
+            return true;
+        }
+        let v = self.add_vertex(p);
+        let mut cavity = vec![self.locate(p)];
+        let mut i = 0;
+        while let Some(&t) = cavity.get(i) {
+            i += 1;
+            for n in self.neighbours[t] {
+                if !cavity.contains(&n) && self.conflicts(n, p) {
+                    cavity.push(n);
+                }
+            }
+        }
+        let boundary = self.boundary(&cavity);
+        let created = boundary
+            .iter()
+            .map(|&(a, b, _)| normalized([a, b, v]))
+            .collect();
+        self.retriangulate(&cavity, &boundary, created);
+        true
+    }
+
+    pub fn remove(&mut self, p: Point2<T>) -> bool {
+        let Some(v) = self.index.remove(&Key(p)) else {
+            return false;
+        };
+        self.free_vertices.push(v);
+        if self.finite == 0 {
+            return true;
+        }
+        let star = self.star(v);
+        let boundary = self.boundary(&star);
+        let mut polygon = Vec::with_capacity(boundary.len());
+        let mut current = boundary[0].0;
+        while polygon.len() < boundary.len() {
+            polygon.push(current);
+            let Some(&(_, next, _)) = boundary.iter().find(|e| e.0 == current) else {
+                break;
+            };
+            current = next;
+        }
+        let created = self.fill_hole(polygon);
+        self.retriangulate(&star, &boundary, created);
+        if self.finite == 0 {
+            self.clear_mesh();
+        }
+        true
+    }
+
+    pub fn move_point(&mut self, from: Point2<T>, to: Point2<T>) -> bool {
+        if from == to {
+            return self.contains(from);
+        }
+        if !self.contains(from) || self.contains(to) {
+            return false;
+        }
+        self.remove(from);
+        self.insert(to)
+    }
+
+    pub fn triangles(&self) -> impl Iterator<Item = Triangle<T>> + '_ {
+        self.triangles.iter().filter(|t| t[2] != GHOST).map(|t| {
+            Triangle::new(
+                self.vertices[t[0]],
+                self.vertices[t[1]],
+                self.vertices[t[2]],
+            )
+        })
+    }
+
+    #[must_use]
+    pub fn triangulation(&self) -> Option<Triangulation2<T>> {
+        Some(Triangulation2 {
+            bounding_triangle: get_bounding_triangle(self.points())?,
+            triangles: self.triangles().collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TriangulationAlgorithm, TriangulationOptions, test_utils::random_points, triangulate_with,
+    };
+
+    fn canonical(triangles: impl IntoIterator<Item = Triangle<f64>>) -> Vec<[Point2<f64>; 3]> {
+        let mut triangles = triangles
+            .into_iter()
+            .map(|t| {
+                let mut vertices = [t.a, t.b, t.c];
+                vertices.sort_by(|a, b| a.total_cmp(b));
+                vertices
+            })
+            .collect::<Vec<_>>();
+        triangles.sort_by(|a, b| {
+            a.iter()
+                .zip(b)
+                .map(|(p, q)| p.total_cmp(q))
+                .find(|o| o.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        triangles
+    }
+
+    fn batch(points: impl IntoIterator<Item = Point2<f64>>) -> Vec<[Point2<f64>; 3]> {
+        let options = TriangulationOptions::new(TriangulationAlgorithm::SweepHull);
+        canonical(triangulate_with(points, options).unwrap().triangles)
+    }
+
+    fn assert_delaunay(triangulation: &DynamicTriangulation2<f64>) {
+        for t in triangulation.triangles() {
+            assert!(t.signed_area() > 0.0);
+            for p in triangulation.points() {
+                if !t.has_point(&p) {
+                    let d = t.circumcenter().distance(p);
+                    assert!(d > t.circumcircle_radius() - 1e-9, "{p:?} inside {t:?}");
+                }
+            }
+        }
+        let mut vertices = triangulation
+            .triangles()
+            .flat_map(|t| [t.a, t.b, t.c])
+            .collect::<Vec<_>>();
+        vertices.sort_by(|a, b| a.total_cmp(b));
+        vertices.dedup();
+        assert_eq!(vertices, triangulation.points().collect::<Vec<_>>());
+        for (t, neighbours) in triangulation.neighbours.iter().enumerate() {
+            if triangulation.triangles[t] == [GHOST; 3] {
+                continue;
+            }
+            for (k, &n) in neighbours.iter().enumerate() {
+                let (a, b) = edge(triangulation.triangles[t], k);
+                assert!((0..3).any(|j| edge(triangulation.triangles[n], j) == (b, a)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_insert_matches_batch() {
+        let points = random_points(60, 3);
+        let mut triangulation = DynamicTriangulation2::new();
+        for &p in &points {
+            assert!(triangulation.insert(p));
+        }
+        assert!(!triangulation.insert(points[10]));
+        assert_eq!(triangulation.len(), 60);
+        assert_delaunay(&triangulation);
+        assert_eq!(canonical(triangulation.triangles()), batch(points));
+    }
+
+    #[test]
+    fn test_insert_outside_hull() {
+        let mut triangulation = random_points(30, 5)
+            .into_iter()
+            .collect::<DynamicTriangulation2<_>>();
+        let far = [
+            [-100.0, -100.0],
+            [200.0, 0.0],
+            [5.0, 1e4],
+            [5.0, -50.0],
+            [6.0, 5.0],
+        ];
+        for p in far.map(Point2::from) {
+            assert!(triangulation.insert(p));
+            assert_delaunay(&triangulation);
+        }
+        assert_eq!(
+            canonical(triangulation.triangles()),
+            batch(triangulation.points())
+        );
+    }
+
+    #[test]
+    fn test_collinear_then_lifted() {
+        let mut triangulation = DynamicTriangulation2::new();
+        for i in 0..5u8 {
+            let x = <f64 as From<u8>>::from(i);
+            assert!(triangulation.insert(Point2::from([x, 2.0 * x])));
+        }
+        assert_eq!(triangulation.triangles().count(), 0);
+        assert!(triangulation.insert(Point2::from([0.0, 5.0])));
+        assert_eq!(triangulation.triangles().count(), 4);
+        assert_delaunay(&triangulation);
+        assert!(triangulation.remove(Point2::from([0.0, 5.0])));
+        assert_eq!(triangulation.triangles().count(), 0);
+        assert!(triangulation.insert(Point2::from([1.0, 0.0])));
+        assert_delaunay(&triangulation);
+    }
+
+    #[test]
+    fn test_remove() {
+        let points = random_points(80, 9);
+        let mut triangulation = points.iter().copied().collect::<DynamicTriangulation2<_>>();
+        for &p in points.iter().step_by(3) {
+            assert!(triangulation.remove(p));
+            assert!(!triangulation.contains(p));
+        }
+        assert!(!triangulation.remove(points[0]));
+        assert_delaunay(&triangulation);
+        let remaining = points
+            .iter()
+            .skip(1)
+            .step_by(3)
+            .copied()
+            .chain(points.iter().skip(2).step_by(3).copied());
+        assert_eq!(canonical(triangulation.triangles()), batch(remaining));
+
+        for &p in &points {
+            triangulation.remove(p);
+        }
+        assert!(triangulation.is_empty());
+        assert!(triangulation.triangulation().is_none());
+    }
+
+    #[test]
+    fn test_move_point() {
+        let points = random_points(40, 21);
+        let mut triangulation = points.iter().copied().collect::<DynamicTriangulation2<_>>();
+        for (i, target) in random_points(20, 77).into_iter().enumerate() {
+            assert!(triangulation.move_point(points[i], target * 1.5));
+        }
+        assert!(!triangulation.move_point(points[0], points[30]));
+        assert!(!triangulation.move_point(points[30], points[31]));
+        assert_eq!(triangulation.len(), 40);
+        assert_delaunay(&triangulation);
+        let snapshot = triangulation.triangulation().unwrap();
+        assert_eq!(canonical(snapshot.triangles), batch(triangulation.points()));
+    }
+
+    #[test]
+    fn test_grid_removal() {
+        let coordinate = |i: u8| <f64 as From<u8>>::from(i);
+        let mut triangulation = (0..6)
+            .flat_map(|i| (0..6).map(move |j| Point2::from([coordinate(i), coordinate(j)])))
+            .collect::<DynamicTriangulation2<_>>();
+        assert!(triangulation.remove(Point2::from([2.0, 2.0])));
+        assert!(triangulation.remove(Point2::from([3.0, 2.0])));
+        assert!(triangulation.remove(Point2::from([0.0, 0.0])));
+        assert!(triangulation.remove(Point2::from([5.0, 3.0])));
+        assert_delaunay(&triangulation);
+        let area: f64 = triangulation
+            .triangles()
+            .map(|t| t.signed_area().abs())
+            .sum();
+        assert!((area - 24.5).abs() < 1e-9);
+    }
+}
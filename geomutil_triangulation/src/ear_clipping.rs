@@ -0,0 +1,407 @@
+use geomutil_util::{Float, Point2, Polygon, Triangle};
+
+fn cross<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> T {
+    (b - a).cross(c - a)
+}
+
+fn in_triangle<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>, p: Point2<T>) -> bool {
+    let zero = T::zero();
+    let (ab, bc, ca) = (cross(a, b, p), cross(b, c, p), cross(c, a, p));
+    (ab >= zero && bc >= zero && ca >= zero) || (ab <= zero && bc <= zero && ca <= zero)
+}
+
+fn corners<T: Float>(
+    points: &[Point2<T>],
+    ring: &[usize],
+    i: usize,
+) -> (Point2<T>, Point2<T>, Point2<T>) {
+    let n = ring.len();
+    (
+        points[ring[(i + n - 1) % n]],
+        points[ring[i]],
+        points[ring[(i + 1) % n]],
+    )
+}
+
+fn clean<T: Float>(points: &[Point2<T>], mut ring: Vec<usize>, clockwise: bool) -> Vec<usize> {
+    let mut i = 0;
+    let mut unchanged = 0;
+    while ring.len() >= 3 && unchanged < ring.len() {
+        i %= ring.len();
+        let (a, b, c) = corners(points, &ring, i);
+        if b == c || cross(a, b, c) == T::zero() {
+            ring.remove(i);
+            unchanged = 0;
+        } else {
+            i += 1;
+            unchanged += 1;
+        }
+    }
+    if ring.len() < 3 {
+        return Vec::new();
+    }
+    let area: T = (0..ring.len())
+        .map(|i| points[ring[i]].cross(points[ring[(i + 1) % ring.len()]]))
+        .sum();
+    if (area < T::zero()) != clockwise {
+        ring.reverse();
+    }
+    ring
+}
+
+fn find_bridge<T: Float>(points: &[Point2<T>], outer: &[usize], m: Point2<T>) -> Option<usize> {
+    let n = outer.len();
+    let mut nearest: Option<(T, usize)> = None;
+    for i in 0..n {
+        let (a, b) = (points[outer[i]], points[outer[(i + 1) % n]]);
+        if a.y == b.y || m.y < a.y.min(b.y) || m.y > a.y.max(b.y) || cross(a, b, m) <= T::zero() {
+            continue;
+        }
+        let x = a.x + (m.y - a.y) * (b.x - a.x) / (b.y - a.y);
+        if x >= m.x && nearest.is_none_or(|(best, _)| x < best) {
+            let endpoint = if a.x > b.x { i } else { (i + 1) % n };
+            nearest = Some((x, endpoint));
+        }
+    }
+    let (x, candidate) = nearest?;
+    let hit = Point2::from([x, m.y]);
+    let p = points[outer[candidate]];
+    let mut bridge = candidate;
+    let mut best_tangent = None;
+    for (j, &index) in outer.iter().enumerate() {
+        let q = points[index];
+        if j == candidate || q == p || q.x <= m.x || !in_triangle(m, hit, p, q) {
+            continue;
+        }
+        let (prev, _, next) = corners(points, outer, j);
+        if cross(prev, q, next) >= T::zero() {
+            continue;
+        }
+        let tangent = (q.y - m.y).abs() / (q.x - m.x);
+        let better = best_tangent.is_none_or(|best| {
+            tangent < best || (tangent == best && q.x > points[outer[bridge]].x)
+        });
+        if better {
+            best_tangent = Some(tangent);
+            bridge = j;
+        }
+    }
+    Some(bridge)
+}
+
+fn bridge_holes<T: Float>(
+    points: &[Point2<T>],
+    mut outer: Vec<usize>,
+    mut holes: Vec<Vec<usize>>,
+) -> Option<Vec<usize>> {
+    let rightmost = |hole: &[usize]| {
+        (0..hole.len())
+            .max_by(|&i, &j| points[hole[i]].total_cmp(&points[hole[j]]))
+            .unwrap_or(0)
+    };
+    holes.sort_by(|a, b| points[b[rightmost(b)]].total_cmp(&points[a[rightmost(a)]]));
+    for hole in holes {
+        let start = rightmost(&hole);
+        let m = hole[start];
+        let k = find_bridge(points, &outer, points[m])?;
+        let p = outer[k];
+        let spliced = hole[start..]
+            .iter()
+            .chain(&hole[..start])
+            .copied()
+            .chain([m, p]);
+        outer.splice(k + 1..k + 1, spliced);
+    }
+    Some(outer)
+}
+
+struct Ring<'a, T: Float> {
+    points: &'a [Point2<T>],
+    ring: Vec<usize>,
+    prev: Vec<usize>,
+    next: Vec<usize>,
+    reflex: Vec<bool>,
+    candidates: Vec<usize>,
+}
+
+impl<'a, T: Float> Ring<'a, T> {
+    fn new(points: &'a [Point2<T>], ring: Vec<usize>) -> Self {
+        let n = ring.len();
+        let mut this = Self {
+            points,
+            ring,
+            prev: (0..n).map(|i| (i + n - 1) % n).collect(),
+            next: (0..n).map(|i| (i + 1) % n).collect(),
+            reflex: vec![false; n],
+            candidates: Vec::new(),
+        };
+        for i in 0..n {
+            this.reflex[i] = !this.convex(i);
+        }
+        this.candidates = (0..n).filter(|&i| this.reflex[i]).collect();
+        this
+    }
+
+    fn corners(&self, i: usize) -> (Point2<T>, Point2<T>, Point2<T>) {
+        (
+            self.points[self.ring[self.prev[i]]],
+            self.points[self.ring[i]],
+            self.points[self.ring[self.next[i]]],
+        )
+    }
+
+    fn convex(&self, i: usize) -> bool {
+        let (a, b, c) = self.corners(i);
+        cross(a, b, c) > T::zero()
+    }
+
+    fn degenerate(&self, i: usize) -> bool {
+        let (a, b, c) = self.corners(i);
+        b == a || b == c || cross(a, b, c) == T::zero()
+    }
+
+    fn is_ear(&self, i: usize) -> bool {
+        let (a, b, c) = self.corners(i);
+        self.convex(i)
+            && self.candidates.iter().all(|&j| {
+                let q = self.points[self.ring[j]];
+                !self.reflex[j] || q == a || q == b || q == c || !in_triangle(a, b, c, q)
+            })
+    }
+
+    fn unlink(&mut self, i: usize) {
+        let (p, n) = (self.prev[i], self.next[i]);
+        self.next[p] = n;
+        self.prev[n] = p;
+        self.reflex[i] = false;
+        for j in [p, n] {
+            let reflex = !self.convex(j);
+            if reflex && !self.reflex[j] {
+                self.candidates.push(j);
+            }
+            self.reflex[j] = reflex;
+        }
+        let reflex = &self.reflex;
+        self.candidates.retain(|&j| reflex[j]);
+    }
+
+    fn triangle(&self, i: usize) -> [usize; 3] {
+        [
+            self.ring[self.prev[i]],
+            self.ring[i],
+            self.ring[self.next[i]],
+        ]
+    }
+}
+
+#[must_use]
+pub fn ear_clipping<T: Float>(
+    exterior: &[Point2<T>],
+    holes: &[Vec<Point2<T>>],
+) -> Option<Vec<[usize; 3]>> {
+    let points = exterior
+        .iter()
+        .chain(holes.iter().flatten())
+        .copied()
+        .collect::<Vec<_>>();
+    let outer = clean(&points, (0..exterior.len()).collect(), false);
+    if outer.is_empty() {
+        return Some(Vec::new());
+    }
+    let mut offset = exterior.len();
+    let holes = holes
+        .iter()
+        .map(|hole| {
+            let ring = clean(&points, (offset..offset + hole.len()).collect(), true);
+            offset += hole.len();
+            ring
+        })
+        .filter(|ring| !ring.is_empty())
+        .collect();
+    let mut ring = Ring::new(&points, bridge_holes(&points, outer, holes)?);
+
+    let mut remaining = ring.ring.len();
+    let mut triangles = Vec::with_capacity(remaining.saturating_sub(2));
+    let mut i = 0;
+    let mut stalled = 0;
+    while remaining > 3 {
+        if ring.degenerate(i) || ring.is_ear(i) {
+            if !ring.degenerate(i) {
+                triangles.push(ring.triangle(i));
+            }
+            let prev = ring.prev[i];
+            ring.unlink(i);
+            remaining -= 1;
+            i = prev;
+            stalled = 0;
+        } else if stalled >= remaining {
+            return None;
+        } else {
+            i = ring.next[i];
+            stalled += 1;
+        }
+    }
+    if ring.convex(i) {
+        triangles.push(ring.triangle(i));
+    }
+    Some(triangles)
+}
+
+#[must_use]
+pub fn ear_clipping_triangles<T: Float>(polygon: &Polygon<T>) -> Option<Vec<Triangle<T>>> {
+    let points = polygon
+        .exterior
+        .iter()
+        .chain(polygon.holes.iter().flatten())
+        .copied()
+        .collect::<Vec<_>>();
+    let triangles = ear_clipping(&polygon.exterior, &polygon.holes)?;
+    Some(
+        triangles
+            .into_iter()
+            .map(|[a, b, c]| Triangle::new(points[a], points[b], points[c]))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ring(coordinates: &[[f64; 2]]) -> Vec<Point2<f64>> {
+        coordinates.iter().map(|&c| Point2::from(c)).collect()
+    }
+
+    fn square(lo: f64, hi: f64) -> Vec<Point2<f64>> {
+        ring(&[[lo, lo], [hi, lo], [hi, hi], [lo, hi]])
+    }
+
+    fn assert_covers(polygon: &Polygon<f64>, expected: usize) {
+        let triangles = ear_clipping_triangles(polygon).expect("triangulable polygon");
+        assert!(triangles.iter().all(|t| t.signed_area() > 0.0));
+        let area: f64 = triangles.iter().map(Triangle::signed_area).sum();
+        assert!((area - polygon.area()).abs() < 1e-9, "{area}");
+        assert_eq!(triangles.len(), expected);
+        assert!(triangles.iter().all(|t| polygon.contains(t.centroid())));
+    }
+
+    #[test]
+    fn test_simple_polygons() {
+        assert_covers(&Polygon::new(square(0.0, 1.0), Vec::new()), 2);
+        let l_shape = ring(&[
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [2.0, 1.0],
+            [1.0, 1.0],
+            [1.0, 2.0],
+            [0.0, 2.0],
+        ]);
+        assert_covers(&Polygon::new(l_shape.clone(), Vec::new()), 4);
+        let clockwise = l_shape.into_iter().rev().collect();
+        assert_covers(&Polygon::new(clockwise, Vec::new()), 4);
+        let comb = ring(&[
+            [0.0, 0.0],
+            [5.0, 0.0],
+            [5.0, 3.0],
+            [4.0, 1.0],
+            [3.0, 3.0],
+            [2.0, 1.0],
+            [1.0, 3.0],
+            [0.0, 1.0],
+        ]);
+        assert_covers(&Polygon::new(comb, Vec::new()), 6);
+        assert_eq!(ear_clipping::<f64>(&[], &[]), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_holes() {
+        assert_covers(&Polygon::new(square(0.0, 4.0), vec![square(1.0, 3.0)]), 8);
+        let holes = vec![
+            ring(&[[1.0, 1.0], [2.0, 1.0], [2.0, 2.0], [1.0, 2.0]]),
+            ring(&[[3.0, 1.0], [3.0, 3.0], [4.0, 3.0], [4.0, 1.0]]),
+            ring(&[[1.0, 3.5], [2.0, 3.5], [1.5, 4.5]]),
+        ];
+        assert_covers(&Polygon::new(square(0.0, 5.0), holes), 4 + 4 + 4 + 3 + 4);
+
+        let star = (0..24u8)
+            .map(|i| {
+                let angle = std::f64::consts::PI * <f64 as From<u8>>::from(i) / 12.0;
+                let radius = if i % 2 == 0 { 10.0 } else { 7.0 };
+                Point2::from([radius * angle.cos(), radius * angle.sin()])
+            })
+            .collect::<Vec<_>>();
+        let holes = [[-3.0, -1.0], [0.5, 2.0], [2.0, -3.0], [-1.0, 3.5]]
+            .map(|[x, y]| ring(&[[x, y], [x, y + 1.0], [x + 1.5, y + 1.2], [x + 1.0, y]]))
+            .to_vec();
+        assert_covers(&Polygon::new(star, holes), 24 + 16 + 8 - 2);
+    }
+
+    #[test]
+    fn test_degenerate_vertices() {
+        let exterior = ring(&[
+            [0.0, 0.0],
+            [0.5, 0.0],
+            [1.0, 0.0],
+            [1.0, 0.0],
+            [1.0, 1.0],
+            [0.5, 1.0],
+            [0.0, 1.0],
+            [0.0, 0.5],
+            [0.0, 0.0],
+        ]);
+        let triangles = ear_clipping(&exterior, &[]).expect("triangulable polygon");
+        let area: f64 = triangles
+            .iter()
+            .map(|&[a, b, c]| Triangle::new(exterior[a], exterior[b], exterior[c]).signed_area())
+            .sum();
+        assert!((area - 1.0).abs() < 1e-12);
+        assert!(
+            triangles
+                .iter()
+                .all(|t| t.iter().all(|&i| i < exterior.len()))
+        );
+        assert_eq!(triangles.len(), 2);
+
+        let hole = ring(&[
+            [0.25, 0.25],
+            [0.25, 0.75],
+            [0.75, 0.75],
+            [0.75, 0.25],
+            [0.75, 0.25],
+        ]);
+        assert_covers(&Polygon::new(exterior.clone(), vec![hole]), 8);
+        let flat = ring(&[[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]]);
+        assert_eq!(ear_clipping(&flat, &[]), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_untriangulable() {
+        let outside = ring(&[[5.0, 0.25], [5.0, 0.75], [5.5, 0.75], [5.5, 0.25]]);
+        assert_eq!(ear_clipping(&square(0.0, 1.0), &[outside]), None);
+        let crossing = ring(&[
+            [0.0, 0.0],
+            [4.0, 0.0],
+            [4.0, 4.0],
+            [3.0, 4.0],
+            [3.0, -1.0],
+            [1.0, -1.0],
+            [1.0, 4.0],
+            [0.0, 4.0],
+        ]);
+        assert_eq!(ear_clipping(&crossing, &[]), None);
+    }
+
+    #[test]
+    fn test_large_comb() {
+        let teeth = 1000u32;
+        let mut exterior = ring(&[[0.0, 0.0], [<f64 as From<u32>>::from(teeth), 0.0]]);
+        for i in (1..=teeth).rev() {
+            let x = <f64 as From<u32>>::from(i);
+            exterior.push(Point2::from([x, 3.0]));
+            exterior.push(Point2::from([x - 0.5, 1.0]));
+        }
+        exterior.push(Point2::from([0.0, 3.0]));
+        let polygon = Polygon::new(exterior, Vec::new());
+        assert_covers(&polygon, polygon.exterior.len() - 2);
+    }
+}
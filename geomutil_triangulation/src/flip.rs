@@ -0,0 +1,246 @@
+use geomutil_util::{Edge2, Float, Point2, Triangle};
+
+use crate::{Triangulation2, locate::triangle_neighbours};
+
+pub(crate) fn in_circle<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>, d: Point2<T>) -> bool {
+    let (a, b, c) = (a - d, b - d, c - d);
+    let (la, lb, lc) = (a.dot(a), b.dot(b), c.dot(c));
+    let (ab, bc, ca) = (a.cross(b), b.cross(c), c.cross(a));
+    let det = la * bc + lb * ca + lc * ab;
+    let scale = la * bc.abs() + lb * ca.abs() + lc * ab.abs();
+    det > scale * T::EPSILON * T::from(16.0)
+}
+
+pub(crate) fn counter_clockwise<T: Float>(t: &Triangle<T>) -> [Point2<T>; 3] {
+    if t.signed_area() < T::zero() {
+        [t.a, t.c, t.b]
+    } else {
+        [t.a, t.b, t.c]
+    }
+}
+
+fn opposite<T: Float>(t: &Triangle<T>, edge: &Edge2<T>) -> Point2<T> {
+    [t.a, t.b, t.c]
+        .into_iter()
+        .find(|&p| p != edge.a && p != edge.b)
+        .unwrap_or(t.c)
+}
+
+fn is_legal<T: Float>(t: &Triangle<T>, d: Point2<T>) -> bool {
+    let [a, b, c] = counter_clockwise(t);
+    !in_circle(a, b, c, d)
+}
+
+fn flipped<T: Float>(
+    first: &Triangle<T>,
+    second: &Triangle<T>,
+    edge: &Edge2<T>,
+) -> Option<[Triangle<T>; 2]> {
+    let c = opposite(first, edge);
+    let d = opposite(second, edge);
+    let side = |o: Point2<T>, p: Point2<T>, q: Point2<T>| (p - o).cross(q - o);
+    let zero = T::zero();
+    let crosses = side(edge.a, edge.b, c) * side(edge.a, edge.b, d) < zero
+        && side(c, d, edge.a) * side(c, d, edge.b) < zero;
+    crosses.then(|| [Triangle::new(c, d, edge.a), Triangle::new(d, c, edge.b)])
+}
+
+impl<T: Float> Triangulation2<T> {
+    fn edge_triangles(&self, edge: &Edge2<T>) -> Vec<usize> {
+        self.triangles
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| edge.a != edge.b && t.has_point(&edge.a) && t.has_point(&edge.b))
+            .map(|(i, _)| i)
+            .take(3)
+            .collect()
+    }
+
+    /// Finds the triangles on `edge` by scanning every triangle, so each call is O(n).
+    /// Use [`Triangulation2::legalize`] to repair a whole mesh; it builds adjacency once.
+    #[must_use]
+    pub fn is_locally_delaunay(&self, edge: &Edge2<T>) -> Option<bool> {
+        match self.edge_triangles(edge)[..] {
+            [_] => Some(true),
+            [first, second] => Some(is_legal(
+                &self.triangles[first],
+                opposite(&self.triangles[second], edge),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Finds the triangles on `edge` by scanning every triangle, so each call is O(n).
+    /// Use [`Triangulation2::legalize`] to repair a whole mesh; it builds adjacency once.
+    pub fn flip_edge(&mut self, edge: &Edge2<T>) -> bool {
+        let [first, second] = self.edge_triangles(edge)[..] else {
+            return false;
+        };
+        let Some([a, b]) = flipped(&self.triangles[first], &self.triangles[second], edge) else {
+            return false;
+        };
+        self.triangles[first] = a;
+        self.triangles[second] = b;
+        true
+    }
+
+    pub fn legalize(&mut self) -> usize {
+        let mut neighbours = triangle_neighbours(&self.triangles);
+        let mut stack = neighbours
+            .iter()
+            .enumerate()
+            .flat_map(|(t, adjacent)| {
+                adjacent
+                    .iter()
+                    .enumerate()
+                    .filter(move |(_, u)| u.is_some_and(|u| t < u))
+                    .map(move |(k, _)| (t, k))
+            })
+            .collect::<Vec<_>>();
+        let mut flips = 0;
+        while let Some((t, k)) = stack.pop() {
+            let Some(u) = neighbours[t][k] else {
+                continue;
+            };
+            let edge = self.triangles[t].edges()[k];
+            if is_legal(&self.triangles[t], opposite(&self.triangles[u], &edge)) {
+                continue;
+            }
+            let Some([first, second]) = flipped(&self.triangles[t], &self.triangles[u], &edge)
+            else {
+                continue;
+            };
+            let (a, b) = (edge.a, edge.b);
+            let (c, d) = (first.a, first.b);
+            let across = |triangle: usize, p: Point2<T>, q: Point2<T>| {
+                let k = edge_index(&self.triangles[triangle], p, q)?;
+                neighbours[triangle][k]
+            };
+            let (ad, ca) = (across(u, a, d), across(t, c, a));
+            let (cb, bd) = (across(t, c, b), across(u, b, d));
+            self.triangles[t] = first;
+            self.triangles[u] = second;
+            neighbours[t] = [Some(u), ad, ca];
+            neighbours[u] = [Some(t), cb, bd];
+            for (outer, from, to) in [(ad, u, t), (cb, t, u)] {
+                if let Some(slot) =
+                    outer.and_then(|w| neighbours[w].iter_mut().find(|slot| **slot == Some(from)))
+                {
+                    *slot = Some(to);
+                }
+            }
+            stack.extend([(t, 1), (t, 2), (u, 1), (u, 2)]);
+            flips += 1;
+        }
+        flips
+    }
+}
+
+fn edge_index<T: Float>(t: &Triangle<T>, p: Point2<T>, q: Point2<T>) -> Option<usize> {
+    t.edges()
+        .iter()
+        .position(|e| (e.a == p && e.b == q) || (e.a == q && e.b == p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::random_points, triangulate};
+
+    fn canonical(triangles: &[Triangle<f64>]) -> Vec<[Point2<f64>; 3]> {
+        let mut triangles = triangles
+            .iter()
+            .map(|t| {
+                let mut vertices = [t.a, t.b, t.c];
+                vertices.sort_by(|a, b| a.total_cmp(b));
+                vertices
+            })
+            .collect::<Vec<_>>();
+        triangles.sort_by(|a, b| {
+            a.iter()
+                .zip(b)
+                .map(|(p, q)| p.total_cmp(q))
+                .find(|o| o.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        triangles
+    }
+
+    #[test]
+    fn test_flip_edge() {
+        let a = Point2::from([0.0, 0.0]);
+        let b = Point2::from([4.0, 0.0]);
+        let c = Point2::from([2.0, 1.0]);
+        let d = Point2::from([2.0, -1.0]);
+        let mut triangulation = Triangulation2 {
+            bounding_triangle: Triangle::new(a, b, c),
+            triangles: vec![Triangle::new(a, b, c), Triangle::new(b, a, d)],
+        };
+        let ab = Edge2::new(a, b);
+        let cd = Edge2::new(c, d);
+        assert_eq!(triangulation.is_locally_delaunay(&ab), Some(false));
+        assert_eq!(
+            triangulation.is_locally_delaunay(&Edge2::new(a, c)),
+            Some(true)
+        );
+        assert_eq!(triangulation.is_locally_delaunay(&cd), None);
+
+        assert!(triangulation.flip_edge(&ab));
+        assert_eq!(triangulation.is_locally_delaunay(&ab), None);
+        assert_eq!(triangulation.is_locally_delaunay(&cd), Some(true));
+        assert!(!triangulation.flip_edge(&Edge2::new(b, c)));
+        assert_eq!(triangulation.legalize(), 0);
+
+        let e = Point2::from([6.0, -0.5]);
+        let mut reflex = Triangulation2 {
+            bounding_triangle: Triangle::new(a, b, c),
+            triangles: vec![Triangle::new(c, b, a), Triangle::new(a, b, e)],
+        };
+        let before = canonical(&reflex.triangles);
+        assert_eq!(reflex.is_locally_delaunay(&ab), Some(true));
+        assert!(!reflex.flip_edge(&ab));
+        assert_eq!(canonical(&reflex.triangles), before);
+        assert_eq!(reflex.legalize(), 0);
+    }
+
+    #[test]
+    fn test_legalize() {
+        let points = random_points(60, 5);
+        let mut triangulation = triangulate(points).unwrap();
+        let delaunay = canonical(&triangulation.triangles);
+        assert_eq!(triangulation.legalize(), 0);
+
+        let mut flips = 0;
+        for i in 0..200 {
+            let t = (i * 37) % triangulation.triangles.len();
+            let edge = triangulation.triangles[t].edges()[i % 3];
+            if triangulation.flip_edge(&edge) {
+                flips += 1;
+            }
+        }
+        assert!(flips > 50);
+        assert_ne!(canonical(&triangulation.triangles), delaunay);
+        assert!(triangulation.legalize() > 0);
+        assert_eq!(canonical(&triangulation.triangles), delaunay);
+    }
+
+    #[test]
+    fn test_legalize_fan() {
+        let points = (0..150u32)
+            .map(|i| {
+                let x = <f64 as From<u32>>::from(i) / 10.0 - 7.0;
+                Point2::from([x, x * x / 4.0 + x / 3.0])
+            })
+            .collect::<Vec<_>>();
+        let delaunay = canonical(&triangulate(points.clone()).unwrap().triangles);
+        let mut fan = Triangulation2 {
+            bounding_triangle: Triangle::new(points[0], points[1], points[2]),
+            triangles: (1..points.len() - 1)
+                .map(|i| Triangle::new(points[0], points[i], points[i + 1]))
+                .collect(),
+        };
+        assert!(fan.legalize() > points.len());
+        assert_eq!(canonical(&fan.triangles), delaunay);
+        assert_eq!(fan.legalize(), 0);
+    }
+}
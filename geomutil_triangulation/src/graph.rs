@@ -0,0 +1,263 @@
+use std::cmp::Ordering;
+
+use geomutil_util::{Float, KdTree, Point2};
+
+use crate::{TriangulationAlgorithm, TriangulationFloat, TriangulationOptions, triangulate_with};
+
+pub(crate) struct DisjointSet {
+    parents: Vec<usize>,
+}
+
+impl DisjointSet {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parents: (0..n).collect(),
+        }
+    }
+
+    pub fn find(&mut self, mut i: usize) -> usize {
+        while self.parents[i] != i {
+            self.parents[i] = self.parents[self.parents[i]];
+            i = self.parents[i];
+        }
+        i
+    }
+
+    pub fn union(&mut self, i: usize, j: usize) -> bool {
+        let (i, j) = (self.find(i), self.find(j));
+        if i == j {
+            return false;
+        }
+        self.parents[i.max(j)] = i.min(j);
+        true
+    }
+}
+
+const fn ordered(i: usize, j: usize) -> (usize, usize) {
+    if i < j { (i, j) } else { (j, i) }
+}
+
+pub(crate) fn sorted_indices<T: Float>(points: &[Point2<T>]) -> Vec<usize> {
+    let mut indices = (0..points.len()).collect::<Vec<_>>();
+    indices.sort_by(|&i, &j| points[i].total_cmp(&points[j]).then(i.cmp(&j)));
+    indices
+}
+
+fn find_index<T: Float>(points: &[Point2<T>], sorted: &[usize], p: Point2<T>) -> Option<usize> {
+    let position = sorted.partition_point(|&i| points[i].total_cmp(&p) == Ordering::Less);
+    sorted
+        .get(position)
+        .copied()
+        .filter(|&i| points[i].total_cmp(&p) == Ordering::Equal)
+}
+
+fn triangles<T: TriangulationFloat>(points: &[Point2<T>]) -> Vec<[usize; 3]> {
+    let sorted = sorted_indices(points);
+    triangulate_with(
+        points.iter().copied(),
+        TriangulationOptions::new(TriangulationAlgorithm::SweepHull),
+    )
+    .map(|triangulation| {
+        triangulation
+            .triangles
+            .iter()
+            .filter_map(|t| {
+                Some([
+                    find_index(points, &sorted, t.a)?,
+                    find_index(points, &sorted, t.b)?,
+                    find_index(points, &sorted, t.c)?,
+                ])
+            })
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+#[must_use]
+pub fn delaunay_edges<T: TriangulationFloat>(points: &[Point2<T>]) -> Vec<(usize, usize)> {
+    let triangles = triangles(points);
+    let mut edges = if triangles.is_empty() {
+        let mut sorted = sorted_indices(points);
+        sorted.dedup_by(|i, j| points[*i] == points[*j]);
+        sorted.windows(2).map(|w| ordered(w[0], w[1])).collect()
+    } else {
+        triangles
+            .iter()
+            .flat_map(|&[a, b, c]| [ordered(a, b), ordered(b, c), ordered(c, a)])
+            .collect::<Vec<_>>()
+    };
+    edges.sort_unstable();
+    edges.dedup();
+    edges
+}
+
+#[must_use]
+pub fn gabriel_graph<T: TriangulationFloat>(points: &[Point2<T>]) -> Vec<(usize, usize)> {
+    let mut blocked = triangles(points)
+        .iter()
+        .flat_map(|&[a, b, c]| [(a, b, c), (b, c, a), (c, a, b)])
+        .filter(|&(i, j, k)| (points[i] - points[k]).dot(points[j] - points[k]) < T::zero())
+        .map(|(i, j, _)| ordered(i, j))
+        .collect::<Vec<_>>();
+    blocked.sort_unstable();
+    delaunay_edges(points)
+        .into_iter()
+        .filter(|edge| blocked.binary_search(edge).is_err())
+        .collect()
+}
+
+#[must_use]
+pub fn relative_neighbourhood_graph<T: TriangulationFloat>(
+    points: &[Point2<T>],
+) -> Vec<(usize, usize)> {
+    let tree = KdTree::new(points.iter().copied());
+    gabriel_graph(points)
+        .into_iter()
+        .filter(|&(i, j)| {
+            let length = points[i].distance_squared(points[j]);
+            tree.within_radius(points[i], length.sqrt())
+                .into_iter()
+                .all(|k| {
+                    points[i].distance_squared(points[k]) >= length
+                        || points[j].distance_squared(points[k]) >= length
+                })
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn euclidean_minimum_spanning_tree<T: TriangulationFloat>(
+    points: &[Point2<T>],
+) -> Vec<(usize, usize)> {
+    let mut edges = delaunay_edges(points);
+    edges.sort_by(|&(a, b), &(c, d)| {
+        points[a]
+            .distance_squared(points[b])
+            .total_cmp(&points[c].distance_squared(points[d]))
+    });
+    let mut components = DisjointSet::new(points.len());
+    edges
+        .into_iter()
+        .filter(|&(i, j)| components.union(i, j))
+        .collect()
+}
+
+#[must_use]
+pub fn k_nearest_graph<T: Float>(points: &[Point2<T>], k: usize) -> Vec<(usize, usize)> {
+    let tree = KdTree::new(points.iter().copied());
+    points
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &p)| {
+            tree.k_nearest(p, k + 1)
+                .into_iter()
+                .filter(move |&j| j != i)
+                .take(k)
+                .map(move |j| (i, j))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    fn random_points(n: usize) -> Vec<Point2<f64>> {
+        test_utils::random_points(n, 7)
+    }
+
+    fn brute_force(
+        points: &[Point2<f64>],
+        blocks: impl Fn(Point2<f64>, Point2<f64>, Point2<f64>) -> bool,
+    ) -> Vec<(usize, usize)> {
+        let n = points.len();
+        (0..n)
+            .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+            .filter(|&(i, j)| {
+                (0..n).all(|k| k == i || k == j || !blocks(points[i], points[j], points[k]))
+            })
+            .collect()
+    }
+
+    fn length(points: &[Point2<f64>], edges: &[(usize, usize)]) -> f64 {
+        edges
+            .iter()
+            .map(|&(i, j)| points[i].distance(points[j]))
+            .sum()
+    }
+
+    fn is_subset(a: &[(usize, usize)], b: &[(usize, usize)]) -> bool {
+        a.iter().all(|edge| b.contains(edge))
+    }
+
+    #[test]
+    fn test_proximity_graphs() {
+        let points = random_points(80);
+        let delaunay = delaunay_edges(&points);
+        let gabriel = gabriel_graph(&points);
+        let rng = relative_neighbourhood_graph(&points);
+        let emst = euclidean_minimum_spanning_tree(&points);
+
+        assert_eq!(
+            gabriel,
+            brute_force(&points, |a, b, c| (a - c).dot(b - c) < 0.0)
+        );
+        assert_eq!(
+            rng,
+            brute_force(&points, |a, b, c| {
+                let length = a.distance_squared(b);
+                a.distance_squared(c) < length && b.distance_squared(c) < length
+            })
+        );
+        assert_eq!(emst.len(), points.len() - 1);
+        assert!(is_subset(&emst, &rng));
+        assert!(is_subset(&rng, &gabriel));
+        assert!(is_subset(&gabriel, &delaunay));
+
+        let mut in_tree = vec![false; points.len()];
+        let mut distances = vec![f64::INFINITY; points.len()];
+        distances[0] = 0.0;
+        let mut prim = 0.0;
+        for _ in 0..points.len() {
+            let i = (0..points.len())
+                .filter(|&i| !in_tree[i])
+                .min_by(|&i, &j| distances[i].total_cmp(&distances[j]))
+                .unwrap();
+            in_tree[i] = true;
+            prim += distances[i];
+            for j in 0..points.len() {
+                distances[j] = distances[j].min(points[i].distance(points[j]));
+            }
+        }
+        assert!((length(&points, &emst) - prim).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_k_nearest_graph() {
+        let points = random_points(50);
+        let graph = k_nearest_graph(&points, 3);
+        assert_eq!(graph.len(), 150);
+        for (i, p) in points.iter().enumerate() {
+            let mut others = (0..points.len()).filter(|&j| j != i).collect::<Vec<_>>();
+            others.sort_by(|&a, &b| p.distance(points[a]).total_cmp(&p.distance(points[b])));
+            let neighbours = graph
+                .iter()
+                .filter(|edge| edge.0 == i)
+                .map(|edge| edge.1)
+                .collect::<Vec<_>>();
+            assert_eq!(neighbours, others[..3]);
+        }
+    }
+
+    #[test]
+    fn test_collinear_points() {
+        let points = [3.0, 0.0, 2.0, 1.0, 2.0].map(|x| Point2::from([x, 2.0 * x]));
+        assert_eq!(delaunay_edges(&points), vec![(0, 2), (1, 3), (2, 3)]);
+        assert_eq!(
+            euclidean_minimum_spanning_tree(&points),
+            delaunay_edges(&points)
+        );
+        assert!(delaunay_edges::<f64>(&[]).is_empty());
+    }
+}
@@ -0,0 +1,485 @@
+use geomutil_util::{BoundingBox2, Float, Point2, Triangle};
+
+use crate::{Locator, TriangleLocation, Triangulation2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InterpolationMethod {
+    Linear,
+    NaturalNeighbour,
+    CloughTocher,
+}
+
+pub struct Interpolator<'a, T: Float> {
+    triangles: &'a [Triangle<T>],
+    locator: Locator<'a, T>,
+    triangle_vertices: Vec<[usize; 3]>,
+    values: Vec<T>,
+    gradients: Vec<Point2<T>>,
+}
+
+fn find_vertex<T: Float>(vertices: &[Point2<T>], p: Point2<T>) -> Option<usize> {
+    vertices.binary_search_by(|v| v.total_cmp(&p)).ok()
+}
+
+fn convex_area<T: Float>(points: &mut [Point2<T>]) -> T {
+    let Some(center) = Point2::avg(points.iter().copied()) else {
+        return T::zero();
+    };
+    points.sort_by(|a, b| {
+        (*a - center)
+            .polar_angle()
+            .total_cmp(&(*b - center).polar_angle())
+    });
+    let n = points.len();
+    let twice_area: T = (0..n).map(|i| points[i].cross(points[(i + 1) % n])).sum();
+    T::from(0.5) * twice_area.abs()
+}
+
+fn linear_gradient<T: Float>(t: &Triangle<T>, [fa, fb, fc]: [T; 3]) -> Option<Point2<T>> {
+    let ab = t.b - t.a;
+    let ac = t.c - t.a;
+    let det = ab.cross(ac);
+    if det == T::zero() {
+        return None;
+    }
+    let (db, dc) = (fb - fa, fc - fa);
+    Some(Point2::from([
+        (db * ac.y - dc * ab.y) / det,
+        (dc * ab.x - db * ac.x) / det,
+    ]))
+}
+
+pub(crate) struct VertexValues<T: Float> {
+    pub points: Vec<Point2<T>>,
+    pub values: Vec<T>,
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl<T: Float> VertexValues<T> {
+    pub fn new(
+        triangles: &[Triangle<T>],
+        samples: impl IntoIterator<Item = (Point2<T>, T)>,
+    ) -> Option<Self> {
+        let mut samples = samples.into_iter().collect::<Vec<_>>();
+        samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+        samples.dedup_by(|a, b| a.0 == b.0);
+        let points = samples.iter().map(|s| s.0).collect::<Vec<_>>();
+        let values = samples.iter().map(|s| s.1).collect::<Vec<_>>();
+        let triangles = triangles
+            .iter()
+            .map(|t| {
+                Some([
+                    find_vertex(&points, t.a)?,
+                    find_vertex(&points, t.b)?,
+                    find_vertex(&points, t.c)?,
+                ])
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            points,
+            values,
+            triangles,
+        })
+    }
+}
+
+impl<'a, T: Float> Interpolator<'a, T> {
+    pub fn new(
+        triangulation: &'a Triangulation2<T>,
+        samples: impl IntoIterator<Item = (Point2<T>, T)>,
+    ) -> Option<Self> {
+        let triangles = triangulation.triangles.as_slice();
+        let VertexValues {
+            values,
+            triangles: triangle_vertices,
+            ..
+        } = VertexValues::new(triangles, samples)?;
+
+        let mut gradients = vec![Point2::zero(); values.len()];
+        let mut weights = vec![T::zero(); values.len()];
+        for (t, ids) in triangles.iter().zip(&triangle_vertices) {
+            let Some(gradient) = linear_gradient(t, ids.map(|i| values[i])) else {
+                continue;
+            };
+            let area = t.signed_area().abs();
+            for &i in ids {
+                gradients[i] += gradient * area;
+                weights[i] += area;
+            }
+        }
+        for (gradient, weight) in gradients.iter_mut().zip(weights) {
+            if weight > T::zero() {
+                *gradient /= weight;
+            }
+        }
+
+        Some(Self {
+            triangles,
+            locator: triangulation.locator(),
+            triangle_vertices,
+            values,
+            gradients,
+        })
+    }
+
+    #[must_use]
+    pub fn interpolate(&self, p: Point2<T>, method: InterpolationMethod) -> Option<T> {
+        self.interpolate_at(self.locator.locate(p), p, method)
+    }
+
+    fn interpolate_at(
+        &self,
+        location: TriangleLocation,
+        p: Point2<T>,
+        method: InterpolationMethod,
+    ) -> Option<T> {
+        if let TriangleLocation::OnVertex { triangle, vertex } = location {
+            return Some(self.values[self.triangle_vertices[triangle][vertex]]);
+        }
+        match method {
+            InterpolationMethod::Linear => self.linear_in(location.triangle()?, p),
+            InterpolationMethod::NaturalNeighbour => self.natural_neighbour_at(location, p),
+            InterpolationMethod::CloughTocher => self.clough_tocher_in(location.triangle()?, p),
+        }
+    }
+
+    #[must_use]
+    pub fn linear(&self, p: Point2<T>) -> Option<T> {
+        self.interpolate(p, InterpolationMethod::Linear)
+    }
+
+    #[must_use]
+    pub fn natural_neighbour(&self, p: Point2<T>) -> Option<T> {
+        self.interpolate(p, InterpolationMethod::NaturalNeighbour)
+    }
+
+    #[must_use]
+    pub fn clough_tocher(&self, p: Point2<T>) -> Option<T> {
+        self.interpolate(p, InterpolationMethod::CloughTocher)
+    }
+
+    fn vertex_values(&self, triangle: usize) -> [T; 3] {
+        self.triangle_vertices[triangle].map(|i| self.values[i])
+    }
+
+    fn linear_in(&self, triangle: usize, p: Point2<T>) -> Option<T> {
+        self.triangles[triangle].interpolate(p, self.vertex_values(triangle))
+    }
+
+    fn natural_neighbour_at(&self, location: TriangleLocation, p: Point2<T>) -> Option<T> {
+        let start = match location {
+            TriangleLocation::Inside(triangle) => triangle,
+            TriangleLocation::OnEdge { triangle, edge } => {
+                if self.locator.neighbours(triangle)[edge].is_none() {
+                    return self.linear_in(triangle, p);
+                }
+                triangle
+            }
+            TriangleLocation::OnVertex { .. } | TriangleLocation::Outside => return None,
+        };
+
+        let mut cavity = vec![start];
+        let mut stack = vec![start];
+        while let Some(t) = stack.pop() {
+            for n in self.locator.neighbours(t).into_iter().flatten() {
+                if !cavity.contains(&n) && self.triangles[n].is_inside_circumcircle(p) {
+                    cavity.push(n);
+                    stack.push(n);
+                }
+            }
+        }
+
+        let mut regions: Vec<(usize, Vec<Point2<T>>)> = Vec::new();
+        let mut add = |vertex: usize, point: Point2<T>| match regions
+            .iter_mut()
+            .find(|(v, _)| *v == vertex)
+        {
+            Some((_, points)) => points.push(point),
+            None => regions.push((vertex, vec![point])),
+        };
+        for &t in &cavity {
+            let ids = self.triangle_vertices[t];
+            let triangle = &self.triangles[t];
+            for &v in &ids {
+                add(v, triangle.circumcenter());
+            }
+            let corners = [triangle.a, triangle.b, triangle.c];
+            for (k, n) in self.locator.neighbours(t).into_iter().enumerate() {
+                if n.is_some_and(|n| cavity.contains(&n)) {
+                    continue;
+                }
+                let (u, w) = (k, (k + 1) % 3);
+                let center = Triangle::new(p, corners[u], corners[w]).circumcenter();
+                add(ids[u], center);
+                add(ids[w], center);
+            }
+        }
+
+        let mut total = T::zero();
+        let mut weighted = T::zero();
+        for (vertex, mut points) in regions {
+            let area = convex_area(&mut points);
+            total += area;
+            weighted += area * self.values[vertex];
+        }
+        if total > T::zero() && !weighted.is_nan() {
+            Some(weighted / total)
+        } else {
+            self.linear_in(start, p)
+        }
+    }
+
+    fn clough_tocher_in(&self, triangle: usize, p: Point2<T>) -> Option<T> {
+        let t = &self.triangles[triangle];
+        let [b1, b2, b3] = t.barycentric(p)?;
+        let ids = self.triangle_vertices[triangle];
+        let [f1, f2, f3] = ids.map(|i| self.values[i]);
+        let [g1, g2, g3] = ids.map(|i| self.gradients[i]);
+        let e12 = t.b - t.a;
+        let e23 = t.c - t.b;
+        let e31 = t.a - t.c;
+
+        let one = T::one();
+        let two = T::from(2.0);
+        let three = T::from(3.0);
+        let six = T::from(6.0);
+        let half = T::from(0.5);
+
+        let c3000 = f1;
+        let c0300 = f2;
+        let c0030 = f3;
+        let c2100 = (g1.dot(e12) + three * c3000) / three;
+        let c2010 = (three * c3000 - g1.dot(e31)) / three;
+        let c1200 = (three * c0300 - g2.dot(e12)) / three;
+        let c0210 = (g2.dot(e23) + three * c0300) / three;
+        let c1020 = (g3.dot(e31) + three * c0030) / three;
+        let c0120 = (three * c0030 - g3.dot(e23)) / three;
+
+        let c2001 = (c2100 + c2010 + c3000) / three;
+        let c0201 = (c1200 + c0300 + c0210) / three;
+        let c0021 = (c1020 + c0120 + c0030) / three;
+
+        let neighbours = self.locator.neighbours(triangle);
+        let opposite = [neighbours[1], neighbours[2], neighbours[0]];
+        let mut g = [T::zero() - half; 3];
+        for (k, n) in opposite.into_iter().enumerate() {
+            let Some(n) = n else {
+                continue;
+            };
+            let Some(c) = t.barycentric(self.triangles[n].centroid()) else {
+                continue;
+            };
+            let (i, j) = ((k + 2) % 3, (k + 1) % 3);
+            g[k] = (two * c[i] + c[j] - one) / (two - three * c[i] - three * c[j]);
+        }
+
+        let c0111 = (g[0] * (c0030 - c0300 + three * c0210 - three * c0120)
+            + (c0021 + c0201 - c0300 + two * c0210 - c0120))
+            * half;
+        let c1011 = (g[1] * (c3000 - c0030 + three * c1020 - three * c2010)
+            + (c2001 + c0021 - c0030 + two * c1020 - c2010))
+            * half;
+        let c1101 = (g[2] * (c0300 - c3000 + three * c2100 - three * c1200)
+            + (c2001 + c0201 - c3000 + two * c2100 - c1200))
+            * half;
+
+        let c1002 = (c1101 + c1011 + c2001) / three;
+        let c0102 = (c1101 + c0111 + c0201) / three;
+        let c0012 = (c1011 + c0111 + c0021) / three;
+        let c0003 = (c1002 + c0102 + c0012) / three;
+
+        let min = b1.min(b2).min(b3);
+        let (b1, b2, b3, b4) = (b1 - min, b2 - min, b3 - min, three * min);
+        let cube = |x: T| x * x * x;
+        let value = cube(b1) * c3000
+            + three * b1 * b1 * (b2 * c2100 + b3 * c2010 + b4 * c2001)
+            + three * b2 * b2 * (b1 * c1200 + b3 * c0210 + b4 * c0201)
+            + three * b3 * b3 * (b1 * c1020 + b2 * c0120 + b4 * c0021)
+            + three * b4 * b4 * (b1 * c1002 + b2 * c0102 + b3 * c0012)
+            + six * b4 * (b1 * b2 * c1101 + b1 * b3 * c1011 + b2 * b3 * c0111)
+            + cube(b2) * c0300
+            + cube(b3) * c0030
+            + cube(b4) * c0003;
+        Some(value)
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn grid(
+        &self,
+        bbox: &BoundingBox2<T>,
+        columns: usize,
+        rows: usize,
+        method: InterpolationMethod,
+    ) -> Vec<Vec<Option<T>>> {
+        let step = |extent: T, n: usize| {
+            if n > 1 {
+                extent / T::from((n - 1) as f64)
+            } else {
+                T::zero()
+            }
+        };
+        let dimensions = bbox.dimensions();
+        let dx = step(dimensions.x, columns);
+        let dy = step(dimensions.y, rows);
+        let mut hint = None;
+        (0..rows)
+            .map(|j| {
+                (0..columns)
+                    .map(|i| {
+                        let p = bbox.lower
+                            + Point2::from([dx * T::from(i as f64), dy * T::from(j as f64)]);
+                        let location = hint.map_or_else(
+                            || self.locator.locate(p),
+                            |hint| self.locator.locate_from(p, hint),
+                        );
+                        if let Some(triangle) = location.triangle() {
+                            hint = Some(triangle);
+                        }
+                        self.interpolate_at(location, p, method)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<T: Float> Triangulation2<T> {
+    pub fn interpolator(
+        &self,
+        samples: impl IntoIterator<Item = (Point2<T>, T)>,
+    ) -> Option<Interpolator<'_, T>> {
+        Interpolator::new(self, samples)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::random_points, triangulate};
+
+    const METHODS: [InterpolationMethod; 3] = [
+        InterpolationMethod::Linear,
+        InterpolationMethod::NaturalNeighbour,
+        InterpolationMethod::CloughTocher,
+    ];
+
+    fn survey(f: impl Fn(Point2<f64>) -> f64) -> (Triangulation2<f64>, Vec<(Point2<f64>, f64)>) {
+        let mut points = random_points(200, 11);
+        points.extend([
+            Point2::from([0.0, 0.0]),
+            Point2::from([10.0, 0.0]),
+            Point2::from([10.0, 10.0]),
+            Point2::from([0.0, 10.0]),
+        ]);
+        let samples = points.iter().map(|&p| (p, f(p))).collect();
+        (triangulate(points).unwrap(), samples)
+    }
+
+    fn plane(p: Point2<f64>) -> f64 {
+        2.0f64.mul_add(p.x, 3.0f64.mul_add(p.y, 1.0))
+    }
+
+    #[test]
+    fn test_reproduces_linear_field() {
+        let (triangulation, samples) = survey(plane);
+        let interpolator = triangulation.interpolator(samples.iter().copied()).unwrap();
+        for p in random_points(200, 5) {
+            let p = p * 0.9 + 0.5;
+            for method in METHODS {
+                let value = interpolator.interpolate(p, method).unwrap();
+                assert!(
+                    (value - plane(p)).abs() < 1e-8,
+                    "{method:?} at {p:?}: {value}"
+                );
+            }
+        }
+        for &(p, z) in &samples {
+            for method in METHODS {
+                assert_eq!(interpolator.interpolate(p, method), Some(z));
+            }
+        }
+        assert_eq!(interpolator.linear(Point2::from([-1.0, 5.0])), None);
+        assert_eq!(
+            interpolator.natural_neighbour(Point2::from([11.0, 5.0])),
+            None
+        );
+        assert_eq!(interpolator.clough_tocher(Point2::from([5.0, 10.5])), None);
+    }
+
+    #[test]
+    fn test_natural_neighbour_symmetry() {
+        let points = [
+            Point2::from([0.0, 0.0]),
+            Point2::from([2.0, 0.0]),
+            Point2::from([2.0, 2.0]),
+            Point2::from([0.0, 2.0]),
+            Point2::from([1.0, 1.0]),
+        ];
+        let triangulation = triangulate(points).unwrap();
+        let values = [0.0, 0.0, 0.0, 0.0, 4.0];
+        let interpolator = triangulation
+            .interpolator(points.into_iter().zip(values))
+            .unwrap();
+        let near_center = interpolator
+            .natural_neighbour(Point2::from([1.0, 1.0]))
+            .unwrap();
+        assert!((near_center - 4.0).abs() < 1e-12);
+        let left = interpolator
+            .natural_neighbour(Point2::from([0.7, 1.0]))
+            .unwrap();
+        let right = interpolator
+            .natural_neighbour(Point2::from([1.3, 1.0]))
+            .unwrap();
+        let up = interpolator
+            .natural_neighbour(Point2::from([1.0, 1.3]))
+            .unwrap();
+        assert!((left - right).abs() < 1e-12);
+        assert!((left - up).abs() < 1e-12);
+        assert!(left > 0.0 && left < 4.0);
+    }
+
+    #[test]
+    fn test_smooth_field_accuracy() {
+        let f = |p: Point2<f64>| (p.x * 0.4).sin() + (p.y * 0.3).cos();
+        let (triangulation, samples) = survey(f);
+        let interpolator = triangulation.interpolator(samples).unwrap();
+        let mut errors = [0.0; 3];
+        for p in random_points(300, 23) {
+            let p = p * 0.8 + 1.0;
+            for (error, method) in errors.iter_mut().zip(METHODS) {
+                *error += (interpolator.interpolate(p, method).unwrap() - f(p)).abs();
+            }
+        }
+        let [linear, natural, clough_tocher] = errors.map(|e| e / 300.0);
+        assert!(linear < 0.02, "linear: {linear}");
+        assert!(natural < 0.02, "natural: {natural}");
+        assert!(
+            clough_tocher < linear,
+            "clough-tocher: {clough_tocher}, linear: {linear}"
+        );
+    }
+
+    #[test]
+    fn test_missing_values() {
+        let (triangulation, samples) = survey(plane);
+        assert!(
+            triangulation
+                .interpolator(samples.into_iter().skip(1))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let (triangulation, samples) = survey(plane);
+        let interpolator = triangulation.interpolator(samples).unwrap();
+        let bbox = BoundingBox2::new(Point2::from([-1.0, 0.0]), Point2::from([10.0, 10.0]));
+        let grid = interpolator.grid(&bbox, 12, 11, InterpolationMethod::NaturalNeighbour);
+        assert_eq!(grid.len(), 11);
+        assert!(grid.iter().all(|row| row.len() == 12));
+        assert!(grid.iter().all(|row| row[0].is_none()));
+        let value = grid[3][5].unwrap();
+        assert!((value - plane(Point2::from([4.0, 3.0]))).abs() < 1e-8);
+    }
+}
@@ -1,5 +1,33 @@
 mod alpha2d;
+mod cluster;
+mod contour;
 mod delaunay2d;
+mod dynamic;
+mod ear_clipping;
+mod flip;
+mod graph;
+mod interpolate;
+mod locate;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod quality;
+mod sweep_hull;
+#[cfg(test)]
+mod test_utils;
 
 pub use alpha2d::alpha_shape_2d;
-pub use delaunay2d::triangulate;
+pub use cluster::{autoclust, cluster_by_edge_length, single_linkage_clusters};
+pub use contour::{ContourBand, Isoline};
+pub use delaunay2d::{
+    Triangulation2, TriangulationAlgorithm, TriangulationFloat, TriangulationOptions, triangulate,
+    triangulate_with,
+};
+pub use dynamic::DynamicTriangulation2;
+pub use ear_clipping::{ear_clipping, ear_clipping_triangles};
+pub use graph::{
+    delaunay_edges, euclidean_minimum_spanning_tree, gabriel_graph, k_nearest_graph,
+    relative_neighbourhood_graph,
+};
+pub use interpolate::{InterpolationMethod, Interpolator};
+pub use locate::{Locator, TriangleLocation};
+pub use quality::QualityReport;
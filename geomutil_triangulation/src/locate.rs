@@ -0,0 +1,295 @@
+use std::collections::HashSet;
+
+use geomutil_util::{Edge2, Float, KdTree, Point2, Triangle};
+
+use crate::Triangulation2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriangleLocation {
+    Inside(usize),
+    OnEdge { triangle: usize, edge: usize },
+    OnVertex { triangle: usize, vertex: usize },
+    Outside,
+}
+
+impl TriangleLocation {
+    #[must_use]
+    pub const fn triangle(&self) -> Option<usize> {
+        match *self {
+            Self::Inside(triangle)
+            | Self::OnEdge { triangle, .. }
+            | Self::OnVertex { triangle, .. } => Some(triangle),
+            Self::Outside => None,
+        }
+    }
+}
+
+pub fn triangle_neighbours<T: Float>(triangles: &[Triangle<T>]) -> Vec<[Option<usize>; 3]> {
+    let mut edges: Vec<(Edge2<T>, usize, usize)> = triangles
+        .iter()
+        .enumerate()
+        .flat_map(|(i, t)| {
+            t.edges()
+                .into_iter()
+                .enumerate()
+                .map(move |(k, e)| (e.canonical(), i, k))
+        })
+        .collect();
+    edges.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut neighbours = vec![[None; 3]; triangles.len()];
+    for shared in edges.chunk_by(|a, b| a.0 == b.0) {
+        if let [(_, i, ki), (_, j, kj)] = *shared {
+            neighbours[i][ki] = Some(j);
+            neighbours[j][kj] = Some(i);
+        }
+    }
+    neighbours
+}
+
+pub struct Locator<'a, T: Float> {
+    triangles: &'a [Triangle<T>],
+    neighbours: Vec<[Option<usize>; 3]>,
+    centroids: KdTree<2, T>,
+    convex: bool,
+}
+
+fn is_convex<T: Float>(triangles: &[Triangle<T>], neighbours: &[[Option<usize>; 3]]) -> bool {
+    let mut boundary = Vec::new();
+    for (t, adjacent) in triangles.iter().zip(neighbours) {
+        let ccw = (t.b - t.a).cross(t.c - t.a) >= T::zero();
+        for (e, n) in t.edges().into_iter().zip(adjacent) {
+            if n.is_none() {
+                boundary.push(if ccw { (e.a, e.b) } else { (e.b, e.a) });
+            }
+        }
+    }
+    boundary.sort_by(|x, y| x.0.total_cmp(&y.0));
+    if boundary.windows(2).any(|w| w[0].0 == w[1].0) {
+        return false;
+    }
+    let Some(&(first, _)) = boundary.first() else {
+        return true;
+    };
+    let next = |p: Point2<T>| {
+        boundary
+            .binary_search_by(|e| e.0.total_cmp(&p))
+            .ok()
+            .map(|i| boundary[i])
+    };
+    let mut current = first;
+    for _ in 0..boundary.len() {
+        let Some((a, b)) = next(current) else {
+            return false;
+        };
+        let Some((_, c)) = next(b) else {
+            return false;
+        };
+        if (b - a).cross(c - b) < T::zero() {
+            return false;
+        }
+        current = b;
+    }
+    current == first
+}
+
+impl<'a, T: Float> Locator<'a, T> {
+    #[must_use]
+    pub fn new(triangulation: &'a Triangulation2<T>) -> Self {
+        let triangles = triangulation.triangles.as_slice();
+        let neighbours = triangle_neighbours(triangles);
+        Self {
+            triangles,
+            convex: is_convex(triangles, &neighbours),
+            neighbours,
+            centroids: KdTree::new(triangles.iter().map(Triangle::centroid)),
+        }
+    }
+
+    #[must_use]
+    pub fn neighbours(&self, triangle: usize) -> [Option<usize>; 3] {
+        self.neighbours[triangle]
+    }
+
+    #[must_use]
+    pub fn locate(&self, p: Point2<T>) -> TriangleLocation {
+        self.centroids
+            .nearest(p)
+            .map_or(TriangleLocation::Outside, |start| {
+                self.locate_from(p, start)
+            })
+    }
+
+    #[must_use]
+    pub fn locate_from(&self, p: Point2<T>, start: usize) -> TriangleLocation {
+        let mut current = start;
+        let mut visited = HashSet::from([start]);
+        let mut state = u64::try_from(start).unwrap_or_default();
+        loop {
+            let t = &self.triangles[current];
+            let edges = t.edges();
+            let orientation = edges[0].orientation(t.c);
+            let exits = (0..3)
+                .filter(|&k| {
+                    let side = edges[k].orientation(p);
+                    orientation == T::zero()
+                        || (orientation > T::zero() && side < T::zero())
+                        || (orientation < T::zero() && side > T::zero())
+                })
+                .collect::<Vec<_>>();
+            if exits.is_empty() {
+                return self.classify(current, p);
+            }
+            let candidates = exits
+                .iter()
+                .filter_map(|&k| self.neighbours[current][k])
+                .filter(|n| !visited.contains(n))
+                .collect::<Vec<_>>();
+            if candidates.is_empty() {
+                let blocked = exits.iter().any(|&k| self.neighbours[current][k].is_some());
+                if self.convex && !blocked {
+                    return TriangleLocation::Outside;
+                }
+                return self
+                    .triangles
+                    .iter()
+                    .position(|t| t.contains(p))
+                    .map_or(TriangleLocation::Outside, |i| self.classify(i, p));
+            }
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            current =
+                candidates[usize::try_from(state >> 33).unwrap_or_default() % candidates.len()];
+            visited.insert(current);
+        }
+    }
+
+    fn classify(&self, i: usize, p: Point2<T>) -> TriangleLocation {
+        let t = &self.triangles[i];
+        if let Some(vertex) = [t.a, t.b, t.c].iter().position(|&v| v == p) {
+            return TriangleLocation::OnVertex {
+                triangle: i,
+                vertex,
+            };
+        }
+        t.edges()
+            .iter()
+            .position(|e| e.contains_point(p))
+            .map_or(TriangleLocation::Inside(i), |edge| {
+                TriangleLocation::OnEdge { triangle: i, edge }
+            })
+    }
+}
+
+impl<T: Float> Triangulation2<T> {
+    #[must_use]
+    pub fn locator(&self) -> Locator<'_, T> {
+        Locator::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::random_unit, triangulate};
+
+    fn grid() -> Triangulation2<f64> {
+        let coordinate = |i: u8| <f64 as From<u8>>::from(i);
+        triangulate((0..8).flat_map(|i| {
+            (0..8).map(move |j| {
+                Point2::from([coordinate(i), 0.05f64.mul_add(coordinate(i), coordinate(j))])
+            })
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_locate_inside_and_outside() {
+        let triangulation = grid();
+        let locator = triangulation.locator();
+        let mut next = random_unit(3);
+        for _ in 0..500 {
+            let p = Point2::from([next() * 9.0 - 0.5, next() * 9.0 - 0.5]);
+            let expected = triangulation.triangles.iter().position(|t| t.contains(p));
+            let location = locator.locate(p);
+            match expected {
+                None => assert_eq!(location, TriangleLocation::Outside),
+                Some(_) => {
+                    let i = location.triangle().unwrap();
+                    assert!(triangulation.triangles[i].contains(p));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_locate_on_edges_and_vertices() {
+        let triangulation = grid();
+        let locator = triangulation.locator();
+        let vertex = Point2::from([3.0, 4.15]);
+        match locator.locate(vertex) {
+            TriangleLocation::OnVertex {
+                triangle,
+                vertex: v,
+            } => {
+                let t = &triangulation.triangles[triangle];
+                assert_eq!([t.a, t.b, t.c][v], vertex);
+            }
+            location => panic!("unexpected location {location:?}"),
+        }
+        let on_edge = Point2::from([0.0, 2.5]);
+        match locator.locate_from(on_edge, 0) {
+            TriangleLocation::OnEdge { triangle, edge } => {
+                assert!(triangulation.triangles[triangle].edges()[edge].contains_point(on_edge));
+            }
+            location => panic!("unexpected location {location:?}"),
+        }
+        assert_eq!(
+            locator.locate(Point2::from([-1.0, 2.5])),
+            TriangleLocation::Outside
+        );
+    }
+
+    #[test]
+    fn test_walk_from_every_start() {
+        let triangulation = grid();
+        let locator = triangulation.locator();
+        assert!(locator.convex);
+        let inside = Point2::from([3.3, 4.7]);
+        for start in 0..triangulation.triangles.len() {
+            for p in [[-3.0, 4.0], [12.0, -1.0], [3.5, 40.0]].map(Point2::from) {
+                assert_eq!(locator.locate_from(p, start), TriangleLocation::Outside);
+            }
+            let i = locator.locate_from(inside, start).triangle().unwrap();
+            assert!(triangulation.triangles[i].contains(inside));
+        }
+    }
+
+    #[test]
+    fn test_walk_around_concavity() {
+        let cell = |x: f64, y: f64| {
+            let [a, b, c, d] =
+                [[x, y], [x + 1.0, y], [x + 1.0, y + 1.0], [x, y + 1.0]].map(Point2::from);
+            [Triangle::new(a, b, d), Triangle::new(b, c, d)]
+        };
+        let triangles = [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]
+            .into_iter()
+            .flat_map(|(x, y)| cell(x, y))
+            .collect::<Vec<_>>();
+        let l_shape = Triangulation2 {
+            bounding_triangle: triangles[0].clone(),
+            triangles,
+        };
+        let locator = l_shape.locator();
+        assert!(!locator.convex);
+        for start in 0..l_shape.triangles.len() {
+            for p in [[1.5, 0.5], [0.5, 1.5], [0.2, 0.3], [1.9, 0.1]].map(Point2::from) {
+                let i = locator.locate_from(p, start).triangle().unwrap();
+                assert!(l_shape.triangles[i].contains(p), "{p:?} from {start}");
+            }
+            for p in [[1.5, 1.5], [2.5, 0.5], [0.5, 2.5]].map(Point2::from) {
+                assert_eq!(locator.locate_from(p, start), TriangleLocation::Outside);
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use std::ops::Range;
+
+use geomutil_util::{Float, KdTree, Point2, Triangle};
+use rayon::prelude::*;
+
+use crate::{flip::in_circle, sweep_hull::sweep_hull};
+
+const MIN_STRIP_LEN: usize = 64;
+
+struct Strips<'a, T: Float> {
+    points: &'a [Point2<T>],
+    size: usize,
+    trees: Vec<KdTree<2, T>>,
+}
+
+impl<T: Float + Send + Sync> Strips<'_, T> {
+    fn strip(&self, i: usize) -> usize {
+        i / self.size
+    }
+
+    fn range(&self, strips: Range<usize>) -> Range<usize> {
+        (strips.start * self.size).min(self.points.len())
+            ..(strips.end * self.size).min(self.points.len())
+    }
+
+    fn is_certified(&self, strips: Range<usize>, [a, b, c]: [usize; 3]) -> bool {
+        if [a, b, c].iter().any(|&i| !strips.contains(&self.strip(i))) {
+            return false;
+        }
+        let range = self.range(strips);
+        let t = Triangle::new(self.points[a], self.points[b], self.points[c]);
+        let (center, radius) = (t.circumcenter(), t.circumcircle_radius());
+        let lower = range.start.checked_sub(1).map(|i| self.points[i].x);
+        let upper = self.points.get(range.end).map(|p| p.x);
+        lower.is_none_or(|x| center.x - radius > x) && upper.is_none_or(|x| center.x + radius < x)
+    }
+
+    fn is_empty(&self, [a, b, c]: [usize; 3]) -> bool {
+        let t = Triangle::new(self.points[a], self.points[b], self.points[c]);
+        let (center, radius) = (t.circumcenter(), t.circumcircle_radius());
+        let first = self.points.partition_point(|p| p.x < center.x - radius);
+        let last = self.points.partition_point(|p| p.x <= center.x + radius);
+        (first..last).is_empty()
+            || (self.strip(first)..=self.strip(last - 1)).all(|strip| {
+                let offset = self.range(strip..strip + 1).start;
+                self.trees[strip]
+                    .within_radius(center, radius)
+                    .into_iter()
+                    .map(|i| i + offset)
+                    .all(|i| {
+                        i == a || i == b || i == c || !in_circle(t.a, t.b, t.c, self.points[i])
+                    })
+            })
+    }
+
+    fn triangulate(&self, strips: Range<usize>) -> (Vec<[usize; 3]>, Vec<usize>) {
+        let mid = usize::midpoint(strips.start, strips.end);
+        let (left, right) = (strips.start..mid, mid..strips.end);
+        let leaf = strips.len() == 1;
+        let (mut certified, vertices) = if leaf {
+            (Vec::new(), self.range(strips.clone()).collect::<Vec<_>>())
+        } else {
+            let ((mut certified, mut seam), (right_certified, right_seam)) = rayon::join(
+                || self.triangulate(left.clone()),
+                || self.triangulate(right.clone()),
+            );
+            certified.extend(right_certified);
+            seam.extend(right_seam);
+            (certified, seam)
+        };
+        let local = sweep_hull(&vertices.iter().map(|&i| self.points[i]).collect::<Vec<_>>());
+
+        let mut used = vec![false; vertices.len()];
+        let mut edges = Vec::with_capacity(3 * local.len());
+        for t in &local {
+            for k in 0..3 {
+                used[t[k]] = true;
+                let (a, b) = (t[k], t[(k + 1) % 3]);
+                edges.push((a.min(b), a.max(b)));
+            }
+        }
+        edges.sort_unstable();
+        let hull = edges
+            .chunk_by(|a, b| a == b)
+            .filter(|chunk| chunk.len() == 1)
+            .flat_map(|chunk| [chunk[0].0, chunk[0].1]);
+
+        let mut seam = (0..vertices.len())
+            .filter(|&i| !used[i])
+            .chain(hull)
+            .map(|i| vertices[i])
+            .collect::<Vec<_>>();
+        for t in local {
+            let t = t.map(|i| vertices[i]);
+            if !leaf && (self.is_certified(left.clone(), t) || self.is_certified(right.clone(), t))
+            {
+                continue;
+            }
+            if self.is_certified(strips.clone(), t) && (leaf || self.is_empty(t)) {
+                certified.push(t);
+            } else {
+                seam.extend(t);
+            }
+        }
+        seam.sort_unstable();
+        seam.dedup();
+        (certified, seam)
+    }
+}
+
+pub(crate) fn parallel_sweep_hull<T: Float + Send + Sync>(
+    points: &[Point2<T>],
+    partitions: usize,
+) -> Vec<[usize; 3]> {
+    let partitions = partitions.min(points.len() / MIN_STRIP_LEN).max(1);
+    let size = points.len().div_ceil(partitions).max(1);
+    let partitions = points.len().div_ceil(size);
+    let trees = (0..partitions)
+        .into_par_iter()
+        .map(|strip| {
+            let range = strip * size..((strip + 1) * size).min(points.len());
+            KdTree::new(points[range].iter().copied())
+        })
+        .collect();
+    let strips = Strips {
+        points,
+        size,
+        trees,
+    };
+    strips.triangulate(0..partitions).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    fn random_points(n: usize, seed: u64) -> Vec<Point2<f64>> {
+        Point2::unique(test_utils::random_points(n, seed))
+    }
+
+    fn canonical(mut triangles: Vec<[usize; 3]>) -> Vec<[usize; 3]> {
+        for t in &mut triangles {
+            t.sort_unstable();
+        }
+        triangles.sort_unstable();
+        triangles
+    }
+
+    #[test]
+    fn test_matches_sequential() {
+        for (n, seed) in [(3000, 1), (5000, 2), (700, 3)] {
+            let points = random_points(n, seed);
+            let expected = canonical(sweep_hull(&points));
+            for partitions in [1, 2, 3, 7, 16] {
+                let triangles = canonical(parallel_sweep_hull(&points, partitions));
+                assert_eq!(triangles, expected, "{n} points, {partitions} partitions");
+            }
+        }
+    }
+
+    #[test]
+    fn test_clustered_and_small_inputs() {
+        let mut points = random_points(1500, 4)
+            .into_iter()
+            .map(|p| p * 0.05)
+            .collect::<Vec<_>>();
+        points.extend(random_points(1500, 5).into_iter().map(|p| p * 0.3 + 50.0));
+        let points = Point2::unique(points);
+        assert_eq!(
+            canonical(parallel_sweep_hull(&points, 8)),
+            canonical(sweep_hull(&points))
+        );
+
+        let small = random_points(10, 6);
+        assert_eq!(
+            canonical(parallel_sweep_hull(&small, 4)),
+            canonical(sweep_hull(&small))
+        );
+    }
+
+    #[test]
+    fn test_more_partitions_than_strips() {
+        let points = random_points(8193, 7);
+        assert_eq!(points.len(), 8193);
+        let expected = canonical(sweep_hull(&points));
+        for partitions in [127, 128, 1000] {
+            let triangles = canonical(parallel_sweep_hull(&points, partitions));
+            assert_eq!(triangles, expected, "{partitions} partitions");
+        }
+    }
+
+    #[test]
+    fn test_grid() {
+        let coordinate = |i: u8| <f64 as From<u8>>::from(i);
+        let points = Point2::unique(
+            (0..40)
+                .flat_map(|i| (0..40).map(move |j| Point2::from([coordinate(i), coordinate(j)]))),
+        );
+        for partitions in [2, 5, 9] {
+            let triangles = parallel_sweep_hull(&points, partitions);
+            assert_eq!(triangles.len(), 2 * 39 * 39);
+            let area: f64 = triangles
+                .iter()
+                .map(|&[a, b, c]| {
+                    Triangle::new(points[a], points[b], points[c])
+                        .signed_area()
+                        .abs()
+                })
+                .sum();
+            assert!((area - 39.0 * 39.0).abs() < 1e-9);
+        }
+    }
+}
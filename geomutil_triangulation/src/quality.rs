@@ -0,0 +1,110 @@
+use geomutil_util::{Float, Triangle};
+
+use crate::Triangulation2;
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct QualityReport<T: Float> {
+    pub triangles: usize,
+    pub min_angle: T,
+    pub max_angle: T,
+    pub mean_min_angle: T,
+    pub max_aspect_ratio: T,
+    pub max_radius_edge_ratio: T,
+    pub angle_histogram: Vec<usize>,
+    pub slivers: usize,
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn histogram_bin<T: Float>(angle: T, bins: usize) -> usize {
+    let position = angle.to_degrees() / T::from(180.0) * T::from(bins as f64);
+    (1..bins)
+        .take_while(|&i| T::from(i as f64) <= position)
+        .count()
+}
+
+impl<T: Float> QualityReport<T> {
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new<'a>(
+        triangles: impl IntoIterator<Item = &'a Triangle<T>>,
+        bins: usize,
+        sliver_angle: T,
+    ) -> Self
+    where
+        T: 'a,
+    {
+        let mut report = Self {
+            triangles: 0,
+            min_angle: T::PI,
+            max_angle: T::zero(),
+            mean_min_angle: T::zero(),
+            max_aspect_ratio: T::zero(),
+            max_radius_edge_ratio: T::zero(),
+            angle_histogram: vec![0; bins],
+            slivers: 0,
+        };
+        for t in triangles {
+            let angles = t.angles();
+            let min_angle = angles.into_iter().fold(T::PI, T::min);
+            report.triangles += 1;
+            report.min_angle = report.min_angle.min(min_angle);
+            report.max_angle = angles.into_iter().fold(report.max_angle, T::max);
+            report.mean_min_angle += min_angle;
+            report.max_aspect_ratio = report.max_aspect_ratio.max(t.aspect_ratio());
+            report.max_radius_edge_ratio = report.max_radius_edge_ratio.max(t.radius_edge_ratio());
+            if bins > 0 {
+                for angle in angles {
+                    report.angle_histogram[histogram_bin(angle, bins)] += 1;
+                }
+            }
+            if min_angle < sliver_angle {
+                report.slivers += 1;
+            }
+        }
+        if report.triangles > 0 {
+            report.mean_min_angle /= T::from(report.triangles as f64);
+        } else {
+            report.min_angle = T::zero();
+        }
+        report
+    }
+}
+
+impl<T: Float> Triangulation2<T> {
+    #[must_use]
+    pub fn quality_report(&self, bins: usize, sliver_angle: T) -> QualityReport<T> {
+        QualityReport::new(&self.triangles, bins, sliver_angle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geomutil_util::Point2;
+
+    use super::*;
+    use crate::triangulate;
+
+    #[test]
+    fn test_quality_report() {
+        let points = [
+            Point2::from([0.0, 0.0]),
+            Point2::from([10.0, 0.0]),
+            Point2::from([10.0, 10.0]),
+            Point2::from([0.0, 10.0]),
+            Point2::from([5.0, 0.5]),
+        ];
+        let triangulation = triangulate(points).unwrap();
+        let report = triangulation.quality_report(6, 10.0f64.to_radians());
+        assert_eq!(report.triangles, 4);
+        assert_eq!(report.angle_histogram.len(), 6);
+        assert_eq!(report.angle_histogram.iter().sum::<usize>(), 12);
+        assert_eq!(report.slivers, 1);
+        assert!(report.min_angle < 6.0f64.to_radians());
+        assert!(report.max_angle > 165.0f64.to_radians());
+        assert_eq!(report.angle_histogram[5], 1);
+
+        let empty = QualityReport::new(&[], 4, 0.1);
+        assert_eq!(empty.triangles, 0);
+        assert_eq!(empty.angle_histogram, [0; 4]);
+    }
+}
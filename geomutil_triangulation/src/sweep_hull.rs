@@ -0,0 +1,275 @@
+use geomutil_util::{Float, Point2};
+
+use crate::flip::in_circle;
+
+const NONE: usize = usize::MAX;
+
+fn circumradius_squared<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> Option<T> {
+    let (d, e) = (b - a, c - a);
+    let det = d.cross(e);
+    if det == T::zero() {
+        return None;
+    }
+    let (bl, cl) = (d.dot(d), e.dot(e));
+    let scale = T::from(0.5) / det;
+    let x = (e.y * bl - d.y * cl) * scale;
+    let y = (d.x * cl - e.x * bl) * scale;
+    Some(x * x + y * y)
+}
+
+fn pseudo_angle<T: Float>(d: Point2<T>) -> T {
+    let p = d.x / (d.x.abs() + d.y.abs());
+    let angle = if d.y > T::zero() {
+        T::from(3.0) - p
+    } else {
+        T::one() + p
+    };
+    angle / T::from(4.0)
+}
+
+fn visible<T: Float>(a: Point2<T>, b: Point2<T>, p: Point2<T>) -> bool {
+    (b - a).cross(p - a) < T::zero()
+}
+
+struct SweepHull<'a, T: Float> {
+    points: &'a [Point2<T>],
+    center: Point2<T>,
+    triangles: Vec<usize>,
+    halfedges: Vec<usize>,
+    hull_start: usize,
+    hull_prev: Vec<usize>,
+    hull_next: Vec<usize>,
+    hull_tri: Vec<usize>,
+    hull_hash: Vec<usize>,
+    stack: Vec<usize>,
+}
+
+impl<T: Float> SweepHull<'_, T> {
+    #[allow(clippy::cast_precision_loss)]
+    fn hash_key(&self, p: Point2<T>) -> usize {
+        let size = self.hull_hash.len();
+        let scaled = pseudo_angle(p - self.center) * T::from(size as f64);
+        let (mut lo, mut hi) = (0, size);
+        while lo < hi {
+            let mid = usize::midpoint(lo, hi);
+            if T::from((mid + 1) as f64) <= scaled {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo % size
+    }
+
+    fn link(&mut self, a: usize, b: usize) {
+        self.halfedges[a] = b;
+        if b != NONE {
+            self.halfedges[b] = a;
+        }
+    }
+
+    fn add_triangle(&mut self, vertices: [usize; 3], adjacent: [usize; 3]) -> usize {
+        let t = self.triangles.len();
+        self.triangles.extend(vertices);
+        self.halfedges.extend([NONE; 3]);
+        for (k, halfedge) in adjacent.into_iter().enumerate() {
+            self.link(t + k, halfedge);
+        }
+        t
+    }
+
+    fn legalize(&mut self, mut a: usize) -> usize {
+        let mut ar;
+        loop {
+            let b = self.halfedges[a];
+            let a0 = a - a % 3;
+            ar = a0 + (a + 2) % 3;
+            if b == NONE {
+                match self.stack.pop() {
+                    Some(next) => {
+                        a = next;
+                        continue;
+                    }
+                    None => break,
+                }
+            }
+            let b0 = b - b % 3;
+            let al = a0 + (a + 1) % 3;
+            let bl = b0 + (b + 2) % 3;
+            let [p0, pr, pl, p1] = [ar, a, al, bl].map(|h| self.points[self.triangles[h]]);
+            if in_circle(p0, pr, pl, p1) {
+                self.triangles[a] = self.triangles[bl];
+                self.triangles[b] = self.triangles[ar];
+                let hbl = self.halfedges[bl];
+                if hbl == NONE {
+                    let mut e = self.hull_start;
+                    loop {
+                        if self.hull_tri[e] == bl {
+                            self.hull_tri[e] = a;
+                            break;
+                        }
+                        e = self.hull_prev[e];
+                        if e == self.hull_start {
+                            break;
+                        }
+                    }
+                }
+                self.link(a, hbl);
+                self.link(b, self.halfedges[ar]);
+                self.link(ar, bl);
+                self.stack.push(b0 + (b + 1) % 3);
+            } else {
+                match self.stack.pop() {
+                    Some(next) => a = next,
+                    None => break,
+                }
+            }
+        }
+        ar
+    }
+
+    fn insert(&mut self, i: usize) {
+        let p = self.points[i];
+        let key = self.hash_key(p);
+        let size = self.hull_hash.len();
+        let mut start = NONE;
+        for j in 0..size {
+            start = self.hull_hash[(key + j) % size];
+            if start != NONE && start != self.hull_next[start] {
+                break;
+            }
+        }
+        start = self.hull_prev[start];
+        let mut e = start;
+        loop {
+            let q = self.hull_next[e];
+            if visible(self.points[e], self.points[q], p) {
+                break;
+            }
+            e = q;
+            if e == start {
+                return;
+            }
+        }
+
+        let t = self.add_triangle([e, i, self.hull_next[e]], [NONE, NONE, self.hull_tri[e]]);
+        self.hull_tri[i] = self.legalize(t + 2);
+        self.hull_tri[e] = t;
+
+        let mut n = self.hull_next[e];
+        loop {
+            let q = self.hull_next[n];
+            if !visible(self.points[n], self.points[q], p) {
+                break;
+            }
+            let t = self.add_triangle([n, i, q], [self.hull_tri[i], NONE, self.hull_tri[n]]);
+            self.hull_tri[i] = self.legalize(t + 2);
+            self.hull_next[n] = n;
+            n = q;
+        }
+
+        if e == start {
+            loop {
+                let q = self.hull_prev[e];
+                if !visible(self.points[q], self.points[e], p) {
+                    break;
+                }
+                let t = self.add_triangle([q, i, e], [NONE, self.hull_tri[e], self.hull_tri[q]]);
+                self.legalize(t + 2);
+                self.hull_tri[q] = t;
+                self.hull_next[e] = e;
+                e = q;
+            }
+        }
+
+        self.hull_start = e;
+        self.hull_prev[i] = e;
+        self.hull_next[e] = i;
+        self.hull_prev[n] = i;
+        self.hull_next[i] = n;
+        let key = self.hash_key(p);
+        self.hull_hash[key] = i;
+        let key = self.hash_key(self.points[e]);
+        self.hull_hash[key] = e;
+    }
+}
+
+pub(crate) fn sweep_hull<T: Float>(points: &[Point2<T>]) -> Vec<[usize; 3]> {
+    let n = points.len();
+    let Some(bbox) = Point2::bounding_box(points.iter().copied()) else {
+        return Vec::new();
+    };
+    let closest = |p: Point2<T>, skip: &[usize]| {
+        (0..n).filter(|i| !skip.contains(i)).min_by(|&i, &j| {
+            p.distance_squared(points[i])
+                .total_cmp(&p.distance_squared(points[j]))
+        })
+    };
+    let Some(i0) = closest(bbox.center(), &[]) else {
+        return Vec::new();
+    };
+    let Some(mut i1) = closest(points[i0], &[i0]) else {
+        return Vec::new();
+    };
+    let Some((mut i2, _)) = (0..n)
+        .filter(|&i| i != i0 && i != i1)
+        .filter_map(|i| Some((i, circumradius_squared(points[i0], points[i1], points[i])?)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+    else {
+        return Vec::new();
+    };
+    if (points[i1] - points[i0]).cross(points[i2] - points[i0]) < T::zero() {
+        std::mem::swap(&mut i1, &mut i2);
+    }
+
+    let (a, b, c) = (points[i0], points[i1], points[i2]);
+    let (d, e) = (b - a, c - a);
+    let (bl, cl) = (d.dot(d), e.dot(e));
+    let scale = T::from(0.5) / d.cross(e);
+    let center = a + Point2::from([(e.y * bl - d.y * cl) * scale, (d.x * cl - e.x * bl) * scale]);
+
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| {
+        center
+            .distance_squared(points[i])
+            .total_cmp(&center.distance_squared(points[j]))
+            .then(i.cmp(&j))
+    });
+
+    let mut hull = SweepHull {
+        points,
+        center,
+        triangles: Vec::with_capacity(6 * n),
+        halfedges: Vec::with_capacity(6 * n),
+        hull_start: i0,
+        hull_prev: vec![NONE; n],
+        hull_next: vec![NONE; n],
+        hull_tri: vec![NONE; n],
+        hull_hash: vec![NONE; n.isqrt() + 1],
+        stack: Vec::new(),
+    };
+    hull.hull_next[i0] = i1;
+    hull.hull_next[i1] = i2;
+    hull.hull_next[i2] = i0;
+    hull.hull_prev[i0] = i2;
+    hull.hull_prev[i1] = i0;
+    hull.hull_prev[i2] = i1;
+    hull.hull_tri[i0] = 0;
+    hull.hull_tri[i1] = 1;
+    hull.hull_tri[i2] = 2;
+    for i in [i0, i1, i2] {
+        let key = hull.hash_key(points[i]);
+        hull.hull_hash[key] = i;
+    }
+    hull.add_triangle([i0, i1, i2], [NONE; 3]);
+
+    for i in order {
+        if i != i0 && i != i1 && i != i2 {
+            hull.insert(i);
+        }
+    }
+    hull.triangles
+        .chunks_exact(3)
+        .map(|t| [t[0], t[1], t[2]])
+        .collect()
+}
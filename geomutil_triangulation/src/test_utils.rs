@@ -0,0 +1,18 @@
+use geomutil_util::Point2;
+
+pub fn random_unit(seed: u64) -> impl FnMut() -> f64 {
+    let mut state = seed;
+    move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        <f64 as From<u32>>::from(u32::try_from(state >> 40).unwrap()) / 16_777_216.0
+    }
+}
+
+pub fn random_points(n: usize, seed: u64) -> Vec<Point2<f64>> {
+    let mut next = random_unit(seed);
+    (0..n)
+        .map(|_| Point2::from([next() * 10.0, next() * 10.0]))
+        .collect()
+}
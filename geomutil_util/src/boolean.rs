@@ -0,0 +1,560 @@
+use std::cmp::Ordering;
+
+use crate::{
+    edge::Edge2,
+    point::Point2,
+    polygon::{
+        Location, Polygon, cross, is_on_segment, locate_in_ring, ring_edges, ring_signed_area,
+    },
+    scalar::Float,
+    sweep::segment_intersections,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BooleanOp {
+    Union,
+    Intersection,
+    Difference,
+    Xor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    Inside,
+    Outside,
+    Same,
+    Opposite,
+}
+
+type Segment<T> = (Point2<T>, Point2<T>);
+
+type OwnedSegment<T> = (Segment<T>, usize);
+
+fn cmp_segments<T: Float>(a: &Segment<T>, b: &Segment<T>) -> Ordering {
+    a.0.total_cmp(&b.0).then_with(|| a.1.total_cmp(&b.1))
+}
+
+fn oriented_segments<T: Float>(polygons: &[Polygon<T>]) -> Vec<OwnedSegment<T>> {
+    let mut segments = Vec::new();
+    for (owner, polygon) in polygons.iter().enumerate() {
+        for (i, ring) in polygon.rings().enumerate() {
+            let ccw = ring_signed_area(ring) > T::zero();
+            let reverse = ccw == (i > 0);
+            segments.extend(
+                ring_edges(ring)
+                    .filter(|(a, b)| a != b)
+                    .map(|(a, b)| (if reverse { (b, a) } else { (a, b) }, owner)),
+            );
+        }
+    }
+    segments
+}
+
+fn locate<T: Float>(polygons: &[Polygon<T>], p: Point2<T>) -> Location {
+    polygons
+        .iter()
+        .map(|polygon| polygon.locate(p))
+        .min_by_key(|location| match location {
+            Location::Inside => 0,
+            Location::Boundary => 1,
+            Location::Outside => 2,
+        })
+        .unwrap_or(Location::Outside)
+}
+
+fn crossing_point<T: Float>(
+    a: Point2<T>,
+    b: Point2<T>,
+    c: Point2<T>,
+    d: Point2<T>,
+) -> Option<Point2<T>> {
+    let zero = T::zero();
+    let d1 = cross(a, b, c);
+    let d2 = cross(a, b, d);
+    let d3 = cross(c, d, a);
+    let d4 = cross(c, d, b);
+    let proper = ((d1 > zero && d2 < zero) || (d1 < zero && d2 > zero))
+        && ((d3 > zero && d4 < zero) || (d3 < zero && d4 > zero));
+    proper.then(|| a + (b - a) * (d3 / (d3 - d4)))
+}
+
+fn apply_cuts<T: Float>(
+    segments: &[OwnedSegment<T>],
+    cuts: Vec<Vec<Point2<T>>>,
+) -> Vec<OwnedSegment<T>> {
+    let mut result = Vec::with_capacity(segments.len());
+    for (&((a, b), owner), mut points) in segments.iter().zip(cuts) {
+        points.push(a);
+        points.push(b);
+        points.sort_by(|p, q| a.distance_squared(*p).total_cmp(&a.distance_squared(*q)));
+        points.dedup();
+        result.extend(points.windows(2).map(|w| ((w[0], w[1]), owner)));
+    }
+    result
+}
+
+fn split_segments<T: Float>(
+    subject: &[OwnedSegment<T>],
+    clip: &[OwnedSegment<T>],
+) -> (Vec<OwnedSegment<T>>, Vec<OwnedSegment<T>>) {
+    let segments = subject
+        .iter()
+        .chain(clip)
+        .map(|&(s, _)| s)
+        .collect::<Vec<_>>();
+    let edges = segments
+        .iter()
+        .map(|&(a, b)| Edge2::new(a, b))
+        .collect::<Vec<_>>();
+    let mut cuts = vec![Vec::new(); segments.len()];
+    for (i, j, _) in segment_intersections(&edges) {
+        let ((a, b), (c, d)) = (segments[i], segments[j]);
+        if let Some(p) = crossing_point(a, b, c, d) {
+            cuts[i].push(p);
+            cuts[j].push(p);
+        }
+        cuts[i].extend([c, d].into_iter().filter(|&p| is_on_segment(p, a, b)));
+        cuts[j].extend([a, b].into_iter().filter(|&p| is_on_segment(p, c, d)));
+    }
+    let clip_cuts = cuts.split_off(subject.len());
+    (apply_cuts(subject, cuts), apply_cuts(clip, clip_cuts))
+}
+
+fn dissolve<T: Float>(segments: &[OwnedSegment<T>], polygons: &[Polygon<T>]) -> Vec<Segment<T>> {
+    if polygons.len() < 2 {
+        return segments.iter().map(|&(s, _)| s).collect();
+    }
+    let mut sorted = segments.to_vec();
+    sorted.sort_by(|x, y| cmp_segments(&x.0, &y.0).then(x.1.cmp(&y.1)));
+    let owners = |s: Segment<T>| {
+        let lo = sorted.partition_point(|x| cmp_segments(&x.0, &s) == Ordering::Less);
+        sorted[lo..]
+            .iter()
+            .take_while(move |x| x.0 == s)
+            .map(|&(_, owner)| owner)
+    };
+    segments
+        .iter()
+        .filter(|&&((a, b), owner)| {
+            let midpoint = (a + b) / T::from(2.0);
+            owners((a, b)).next() == Some(owner)
+                && owners((b, a)).all(|other| other == owner)
+                && polygons
+                    .iter()
+                    .enumerate()
+                    .all(|(i, polygon)| i == owner || polygon.locate(midpoint) != Location::Inside)
+        })
+        .map(|&(s, _)| s)
+        .collect()
+}
+
+fn classify<T: Float>(
+    segments: &[Segment<T>],
+    other_segments: &[Segment<T>],
+    other: &[Polygon<T>],
+) -> Vec<Class> {
+    let mut sorted = other_segments.to_vec();
+    sorted.sort_by(cmp_segments);
+    let has = |s: &Segment<T>| sorted.binary_search_by(|x| cmp_segments(x, s)).is_ok();
+    segments
+        .iter()
+        .map(|&(a, b)| {
+            if has(&(a, b)) {
+                Class::Same
+            } else if has(&(b, a)) {
+                Class::Opposite
+            } else if locate(other, (a + b) / T::from(2.0)) == Location::Inside {
+                Class::Inside
+            } else {
+                Class::Outside
+            }
+        })
+        .collect()
+}
+
+fn turn<T: Float>(incoming: Point2<T>, outgoing: Point2<T>) -> T {
+    (incoming.x * outgoing.y - incoming.y * outgoing.x).atan2(incoming.dot(outgoing))
+}
+
+fn stitch<T: Float>(segments: &[Segment<T>]) -> Vec<Vec<Point2<T>>> {
+    let mut order = (0..segments.len()).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| segments[i].0.total_cmp(&segments[j].0));
+    let outgoing = |p: Point2<T>| {
+        let lo = order.partition_point(|&i| segments[i].0.total_cmp(&p) == Ordering::Less);
+        let hi = order.partition_point(|&i| segments[i].0.total_cmp(&p) != Ordering::Greater);
+        &order[lo..hi]
+    };
+    let mut used = vec![false; segments.len()];
+    let mut rings = Vec::new();
+    for first in 0..segments.len() {
+        if used[first] {
+            continue;
+        }
+        let start = segments[first].0;
+        let mut ring = Vec::new();
+        let mut current = first;
+        let closed = loop {
+            used[current] = true;
+            let (a, b) = segments[current];
+            ring.push(a);
+            if b == start {
+                break true;
+            }
+            let next = outgoing(b)
+                .iter()
+                .copied()
+                .filter(|&i| !used[i])
+                .max_by(|&i, &j| {
+                    let ti = turn(b - a, segments[i].1 - b);
+                    let tj = turn(b - a, segments[j].1 - b);
+                    ti.total_cmp(&tj)
+                });
+            match next {
+                Some(next) => current = next,
+                None => break false,
+            }
+        };
+        if !closed {
+            continue;
+        }
+        let ring = simplify_ring(ring);
+        if ring.len() >= 3 && ring_signed_area(&ring) != T::zero() {
+            rings.push(ring);
+        }
+    }
+    rings
+}
+
+fn simplify_ring<T: Float>(mut ring: Vec<Point2<T>>) -> Vec<Point2<T>> {
+    let mut i = 0;
+    while ring.len() >= 3 && i < ring.len() {
+        let n = ring.len();
+        let prev = ring[(i + n - 1) % n];
+        let next = ring[(i + 1) % n];
+        if cross(prev, ring[i], next) == T::zero()
+            && (ring[i] - prev).dot(next - ring[i]) > T::zero()
+        {
+            ring.remove(i);
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+    ring
+}
+
+fn assemble<T: Float>(rings: Vec<Vec<Point2<T>>>) -> Vec<Polygon<T>> {
+    let (mut exteriors, holes): (Vec<_>, Vec<_>) = rings
+        .into_iter()
+        .partition(|ring| ring_signed_area(ring) > T::zero());
+    exteriors.sort_by(|a, b| ring_signed_area(a).total_cmp(&ring_signed_area(b)));
+    let mut polygons = exteriors
+        .into_iter()
+        .map(|exterior| Polygon::new(exterior, Vec::new()))
+        .collect::<Vec<_>>();
+    for hole in holes {
+        let owner = polygons.iter().position(|polygon| {
+            hole.iter()
+                .all(|&p| locate_in_ring(&polygon.exterior, p) != Location::Outside)
+        });
+        match owner {
+            Some(owner) => polygons[owner].holes.push(hole),
+            None => polygons.push(Polygon::new(hole.into_iter().rev().collect(), Vec::new())),
+        }
+    }
+    polygons
+}
+
+#[must_use]
+pub fn boolean_op<T: Float>(
+    subject: &[Polygon<T>],
+    clip: &[Polygon<T>],
+    op: BooleanOp,
+) -> Vec<Polygon<T>> {
+    let (subject_segments, clip_segments) =
+        split_segments(&oriented_segments(subject), &oriented_segments(clip));
+    let subject_segments = dissolve(&subject_segments, subject);
+    let clip_segments = dissolve(&clip_segments, clip);
+    let subject_classes = classify(&subject_segments, &clip_segments, clip);
+    let clip_classes = classify(&clip_segments, &subject_segments, subject);
+
+    let mut selected = Vec::new();
+    for (&(a, b), class) in subject_segments.iter().zip(subject_classes) {
+        match (op, class) {
+            (BooleanOp::Union, Class::Outside | Class::Same)
+            | (BooleanOp::Intersection, Class::Inside | Class::Same)
+            | (BooleanOp::Difference, Class::Outside | Class::Opposite)
+            | (BooleanOp::Xor, Class::Outside) => selected.push((a, b)),
+            (BooleanOp::Xor, Class::Inside) => selected.push((b, a)),
+            _ => {}
+        }
+    }
+    for (&(a, b), class) in clip_segments.iter().zip(clip_classes) {
+        match (op, class) {
+            (BooleanOp::Union | BooleanOp::Xor, Class::Outside)
+            | (BooleanOp::Intersection, Class::Inside) => selected.push((a, b)),
+            (BooleanOp::Difference | BooleanOp::Xor, Class::Inside) => selected.push((b, a)),
+            _ => {}
+        }
+    }
+    assemble(stitch(&selected))
+}
+
+#[must_use]
+pub fn union_all<T: Float>(polygons: impl IntoIterator<Item = Polygon<T>>) -> Vec<Polygon<T>> {
+    let mut layers = polygons.into_iter().map(|p| vec![p]).collect::<Vec<_>>();
+    while layers.len() > 1 {
+        let mut merged = Vec::with_capacity(layers.len().div_ceil(2));
+        let mut layers_iter = layers.into_iter();
+        while let Some(a) = layers_iter.next() {
+            match layers_iter.next() {
+                Some(b) => merged.push(boolean_op(&a, &b, BooleanOp::Union)),
+                None => merged.push(a),
+            }
+        }
+        layers = merged;
+    }
+    layers.pop().unwrap_or_default()
+}
+
+impl<T: Float> Polygon<T> {
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Vec<Self> {
+        boolean_op(
+            std::slice::from_ref(self),
+            std::slice::from_ref(other),
+            BooleanOp::Union,
+        )
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Vec<Self> {
+        boolean_op(
+            std::slice::from_ref(self),
+            std::slice::from_ref(other),
+            BooleanOp::Intersection,
+        )
+    }
+
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        boolean_op(
+            std::slice::from_ref(self),
+            std::slice::from_ref(other),
+            BooleanOp::Difference,
+        )
+    }
+
+    #[must_use]
+    pub fn xor(&self, other: &Self) -> Vec<Self> {
+        boolean_op(
+            std::slice::from_ref(self),
+            std::slice::from_ref(other),
+            BooleanOp::Xor,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x0: f64, y0: f64, x1: f64, y1: f64) -> Polygon<f64> {
+        Polygon::new(
+            vec![
+                Point2::from([x0, y0]),
+                Point2::from([x1, y0]),
+                Point2::from([x1, y1]),
+                Point2::from([x0, y1]),
+            ],
+            Vec::new(),
+        )
+    }
+
+    fn total_area(polygons: &[Polygon<f64>]) -> f64 {
+        polygons.iter().map(Polygon::area).sum()
+    }
+
+    fn assert_approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-10, "wanted: {b}, got: {a}");
+    }
+
+    #[test]
+    fn test_overlapping_squares() {
+        let a = rect(0.0, 0.0, 2.0, 2.0);
+        let b = rect(1.0, 1.0, 3.0, 3.0);
+
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].exterior.len(), 8);
+        assert_approx_eq(total_area(&union), 7.0);
+
+        let intersection = a.intersection(&b);
+        assert_eq!(intersection.len(), 1);
+        assert_eq!(intersection[0].exterior.len(), 4);
+        assert_approx_eq(total_area(&intersection), 1.0);
+
+        let difference = a.difference(&b);
+        assert_eq!(difference.len(), 1);
+        assert_approx_eq(total_area(&difference), 3.0);
+
+        let xor = a.xor(&b);
+        assert_eq!(xor.len(), 2);
+        assert_approx_eq(total_area(&xor), 6.0);
+    }
+
+    #[test]
+    fn test_shared_edge() {
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        let b = rect(1.0, 0.0, 2.0, 1.0);
+
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].exterior.len(), 4);
+        assert_approx_eq(total_area(&union), 2.0);
+
+        assert!(a.intersection(&b).is_empty());
+
+        let difference = a.difference(&b);
+        assert_eq!(difference.len(), 1);
+        assert_approx_eq(total_area(&difference), 1.0);
+    }
+
+    #[test]
+    fn test_partially_shared_edge() {
+        let a = rect(0.0, 0.0, 2.0, 1.0);
+        let b = rect(1.0, 1.0, 3.0, 2.0);
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].exterior.len(), 8);
+        assert_approx_eq(total_area(&union), 4.0);
+        assert!(a.intersection(&b).is_empty());
+    }
+
+    #[test]
+    fn test_touching_vertex() {
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        let b = rect(1.0, 1.0, 2.0, 2.0);
+        let union = a.union(&b);
+        assert_eq!(union.len(), 2);
+        assert_approx_eq(total_area(&union), 2.0);
+        assert!(a.intersection(&b).is_empty());
+    }
+
+    #[test]
+    fn test_hole() {
+        let outer = rect(0.0, 0.0, 4.0, 4.0);
+        let inner = rect(1.0, 1.0, 2.0, 2.0);
+
+        let difference = outer.difference(&inner);
+        assert_eq!(difference.len(), 1);
+        assert_eq!(difference[0].holes.len(), 1);
+        assert_approx_eq(total_area(&difference), 15.0);
+
+        let restored = difference[0].union(&inner);
+        assert_eq!(restored.len(), 1);
+        assert!(restored[0].holes.is_empty());
+        assert_approx_eq(total_area(&restored), 16.0);
+    }
+
+    #[test]
+    fn test_disjoint() {
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        let b = rect(2.0, 0.0, 3.0, 1.0);
+        assert_eq!(a.union(&b).len(), 2);
+        assert!(a.intersection(&b).is_empty());
+        assert_eq!(a.difference(&b), vec![a.clone()]);
+    }
+
+    #[test]
+    fn test_union_all() {
+        let union = union_all([
+            rect(0.0, 0.0, 1.0, 1.0),
+            rect(1.0, 0.0, 2.0, 1.0),
+            rect(0.5, 0.5, 1.5, 2.0),
+            rect(5.0, 5.0, 6.0, 6.0),
+        ]);
+        assert_eq!(union.len(), 2);
+        assert_approx_eq(total_area(&union), 4.0);
+    }
+
+    #[test]
+    fn test_clockwise_input() {
+        let a = rect(0.0, 0.0, 2.0, 2.0);
+        let mut b = rect(1.0, 1.0, 3.0, 3.0);
+        b.exterior.reverse();
+        assert_approx_eq(total_area(&a.union(&b)), 7.0);
+        assert_approx_eq(total_area(&a.intersection(&b)), 1.0);
+    }
+
+    #[test]
+    fn test_orphan_hole() {
+        let mut a = rect(0.0, 0.0, 1.0, 1.0);
+        a.holes.push(rect(5.0, 5.0, 6.0, 6.0).exterior);
+        let b = rect(2.0, 0.0, 3.0, 1.0);
+        let union = a.union(&b);
+        assert_eq!(union.len(), 3);
+        assert!(union.iter().all(|p| p.holes.is_empty()));
+        assert_approx_eq(total_area(&union), 3.0);
+        assert_approx_eq(total_area(&union_all([a, b])), 3.0);
+    }
+
+    #[test]
+    fn test_many_segments() {
+        let circle = |cx: f64, r: f64| {
+            let exterior = (0..500u32)
+                .map(|i| {
+                    let angle = std::f64::consts::TAU * <f64 as From<u32>>::from(i) / 500.0;
+                    Point2::from([cx + r * angle.cos(), r * angle.sin()])
+                })
+                .collect();
+            Polygon::new(exterior, Vec::new())
+        };
+        let (a, b) = (circle(0.0, 1.0), circle(1.0, 1.0));
+        let union = a.union(&b);
+        let intersection = a.intersection(&b);
+        assert_eq!((union.len(), intersection.len()), (1, 1));
+        let lens = 2.0 * std::f64::consts::FRAC_PI_3 - 3.0_f64.sqrt() / 2.0;
+        assert!((total_area(&intersection) - lens).abs() < 1e-4);
+        assert!(
+            (total_area(&union) + total_area(&intersection) - a.area() - b.area()).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_stitch_drops_open_chains() {
+        let [a, b, c, d] = [[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]].map(Point2::from);
+        assert!(stitch(&[(a, b), (b, c), (c, d)]).is_empty());
+        assert_eq!(stitch(&[(a, b), (b, c), (c, d), (d, a)]).len(), 1);
+    }
+
+    #[test]
+    fn test_overlapping_operand_polygons() {
+        let subject = [rect(0.0, 0.0, 2.0, 2.0), rect(1.0, 0.0, 3.0, 2.0)];
+        let far = [rect(5.0, 5.0, 6.0, 6.0)];
+        let union = boolean_op(&subject, &far, BooleanOp::Union);
+        assert_eq!(union.len(), 2);
+        assert!(
+            union
+                .iter()
+                .any(|p| p.exterior.len() == 4 && p.holes.is_empty())
+        );
+        assert_approx_eq(total_area(&union), 7.0);
+
+        let band = [rect(0.5, 0.5, 2.5, 1.5)];
+        let intersection = boolean_op(&subject, &band, BooleanOp::Intersection);
+        assert_eq!(intersection.len(), 1);
+        assert_eq!(intersection[0].exterior.len(), 4);
+        assert_approx_eq(total_area(&intersection), 2.0);
+
+        let difference = boolean_op(&band, &subject, BooleanOp::Difference);
+        assert!(difference.is_empty());
+        let xor = boolean_op(&subject, &band, BooleanOp::Xor);
+        assert_approx_eq(total_area(&xor), 4.0);
+
+        let tiles = [rect(0.0, 0.0, 1.0, 1.0), rect(1.0, 0.0, 2.0, 1.0)];
+        let union = boolean_op(&tiles, &[], BooleanOp::Union);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].exterior.len(), 4);
+    }
+}
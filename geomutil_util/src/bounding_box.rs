@@ -1,4 +1,4 @@
-use std::iter;
+use std::{array, iter};
 
 use crate::{point::Point, scalar::Float};
 
@@ -14,7 +14,10 @@ pub type BoundingBox3<T> = BoundingBox<3, T>;
 impl<const N: usize, T: Float> BoundingBox<N, T> {
     #[must_use]
     pub fn new(a: Point<N, T>, b: Point<N, T>) -> Self {
-        Point::bounding_box([a, b]).unwrap()
+        Self {
+            lower: Point::from(array::from_fn(|i| a[i].min(b[i]))),
+            upper: Point::from(array::from_fn(|i| a[i].max(b[i]))),
+        }
     }
 
     #[must_use]
@@ -24,7 +27,7 @@ impl<const N: usize, T: Float> BoundingBox<N, T> {
 
     #[must_use]
     pub fn center(&self) -> Point<N, T> {
-        Point::avg([self.lower, self.upper]).unwrap()
+        (self.lower + self.upper) / T::from(2.0)
     }
 
     #[must_use]
@@ -38,6 +41,73 @@ impl<const N: usize, T: Float> BoundingBox<N, T> {
     pub fn contains(&self, p: Point<N, T>) -> bool {
         iter::zip(p, iter::zip(self.lower, self.upper)).all(|(x, (lo, hi))| x >= lo && x <= hi)
     }
+
+    #[must_use]
+    pub fn contains_box(&self, other: &Self) -> bool {
+        self.contains(other.lower) && self.contains(other.upper)
+    }
+
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        (0..N).all(|i| self.lower[i] <= other.upper[i] && other.lower[i] <= self.upper[i])
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            lower: Point::from(array::from_fn(|i| self.lower[i].min(other.lower[i]))),
+            upper: Point::from(array::from_fn(|i| self.upper[i].max(other.upper[i]))),
+        }
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.overlaps(other).then(|| Self {
+            lower: Point::from(array::from_fn(|i| self.lower[i].max(other.lower[i]))),
+            upper: Point::from(array::from_fn(|i| self.upper[i].min(other.upper[i]))),
+        })
+    }
+
+    #[must_use]
+    pub fn expand_to_point(&self, p: Point<N, T>) -> Self {
+        self.union(&Self { lower: p, upper: p })
+    }
+
+    #[must_use]
+    pub fn expand_by(&self, margin: T) -> Self {
+        let center = self.center();
+        Self {
+            lower: Point::from(array::from_fn(|i| (self.lower[i] - margin).min(center[i]))),
+            upper: Point::from(array::from_fn(|i| (self.upper[i] + margin).max(center[i]))),
+        }
+    }
+
+    #[must_use]
+    pub fn distance_squared_to_point(&self, p: Point<N, T>) -> T {
+        (0..N)
+            .map(|i| {
+                let d = (self.lower[i] - p[i]).max(p[i] - self.upper[i]);
+                d.max(T::zero()).powi(2)
+            })
+            .sum()
+    }
+
+    #[must_use]
+    pub fn distance_to_point(&self, p: Point<N, T>) -> T {
+        self.distance_squared_to_point(p).sqrt()
+    }
+
+    pub fn corners(&self) -> impl Iterator<Item = Point<N, T>> + '_ {
+        (0..1usize << N).map(|mask| {
+            Point::from(array::from_fn(|i| {
+                if mask & (1 << i) == 0 {
+                    self.lower[i]
+                } else {
+                    self.upper[i]
+                }
+            }))
+        })
+    }
 }
 
 impl<const N: usize, T: Float> From<(Point<N, T>, Point<N, T>)> for BoundingBox<N, T> {
@@ -45,3 +115,89 @@ impl<const N: usize, T: Float> From<(Point<N, T>, Point<N, T>)> for BoundingBox<
         Self::new(value.0, value.1)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::{Point2, Point3};
+
+    fn bbox(lower: [f64; 2], upper: [f64; 2]) -> BoundingBox2<f64> {
+        BoundingBox2::new(Point2::from(lower), Point2::from(upper))
+    }
+
+    #[test]
+    fn test_new() {
+        let b = BoundingBox2::new(Point2::from([2.0, 0.0]), Point2::from([0.0, 3.0]));
+        assert_eq!(b.lower, Point2::from([0.0, 0.0]));
+        assert_eq!(b.upper, Point2::from([2.0, 3.0]));
+        assert_eq!(b.center(), Point2::from([1.0, 1.5]));
+    }
+
+    #[test]
+    fn test_union_intersection() {
+        let a = bbox([0.0, 0.0], [2.0, 2.0]);
+        let b = bbox([1.0, 1.0], [3.0, 4.0]);
+        assert_eq!(a.union(&b), bbox([0.0, 0.0], [3.0, 4.0]));
+        assert_eq!(a.intersection(&b), Some(bbox([1.0, 1.0], [2.0, 2.0])));
+        assert!(a.overlaps(&b));
+
+        let touching = bbox([2.0, 0.0], [3.0, 1.0]);
+        assert!(a.overlaps(&touching));
+        assert_eq!(
+            a.intersection(&touching),
+            Some(bbox([2.0, 0.0], [2.0, 1.0]))
+        );
+
+        let far = bbox([5.0, 5.0], [6.0, 6.0]);
+        assert!(!a.overlaps(&far));
+        assert_eq!(a.intersection(&far), None);
+    }
+
+    #[test]
+    fn test_containment() {
+        let a = bbox([0.0, 0.0], [4.0, 4.0]);
+        assert!(a.contains_box(&bbox([1.0, 1.0], [4.0, 2.0])));
+        assert!(!a.contains_box(&bbox([1.0, 1.0], [5.0, 2.0])));
+        assert!(a.contains_box(&a));
+    }
+
+    #[test]
+    fn test_expand() {
+        let a = bbox([0.0, 0.0], [1.0, 1.0]);
+        assert_eq!(
+            a.expand_to_point(Point2::from([-1.0, 3.0])),
+            bbox([-1.0, 0.0], [1.0, 3.0])
+        );
+        assert_eq!(a.expand_by(0.5), bbox([-0.5, -0.5], [1.5, 1.5]));
+        assert_eq!(a.expand_by(-0.25), bbox([0.25, 0.25], [0.75, 0.75]));
+        let b = bbox([0.0, 0.0], [4.0, 1.0]);
+        assert_eq!(b.expand_by(-1.0), bbox([1.0, 0.5], [3.0, 0.5]));
+        assert_eq!(b.expand_by(-5.0), bbox([2.0, 0.5], [2.0, 0.5]));
+    }
+
+    #[test]
+    fn test_distance() {
+        let a = bbox([0.0, 0.0], [2.0, 2.0]);
+        assert_eq!(a.distance_to_point(Point2::from([1.0, 1.0])), 0.0);
+        assert_eq!(a.distance_to_point(Point2::from([5.0, 1.0])), 3.0);
+        assert_eq!(a.distance_to_point(Point2::from([5.0, 6.0])), 5.0);
+    }
+
+    #[test]
+    fn test_corners() {
+        let a = bbox([0.0, 0.0], [1.0, 2.0]);
+        assert_eq!(
+            a.corners().collect::<Vec<_>>(),
+            [
+                Point2::from([0.0, 0.0]),
+                Point2::from([1.0, 0.0]),
+                Point2::from([0.0, 2.0]),
+                Point2::from([1.0, 2.0]),
+            ]
+        );
+        let b = BoundingBox3::<f64>::new(Point3::zero(), Point3::one());
+        let corners = b.corners().collect::<Vec<_>>();
+        assert_eq!(corners.len(), 8);
+        assert_eq!(Point3::unique(corners).len(), 8);
+    }
+}
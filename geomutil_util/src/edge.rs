@@ -1,6 +1,11 @@
+use std::cmp::Ordering;
+
 use serde::{Deserialize, Serialize};
 
-use crate::{point::Point, scalar::Float};
+use crate::{
+    point::{Point, Point2},
+    scalar::Float,
+};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Edge<const N: usize, T: Float> {
@@ -11,6 +16,21 @@ pub struct Edge<const N: usize, T: Float> {
 pub type Edge2<T> = Edge<2, T>;
 pub type Edge3<T> = Edge<3, T>;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Left,
+    Right,
+    On,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentIntersection<T: Float> {
+    None,
+    Point(Point2<T>),
+    Overlap(Edge2<T>),
+}
+
 impl<const N: usize, T: Float> Edge<N, T> {
     #[must_use]
     pub const fn new(a: Point<N, T>, b: Point<N, T>) -> Self {
@@ -22,6 +42,13 @@ impl<const N: usize, T: Float> Edge<N, T> {
         Self::new(self.a.min(self.b), self.a.max(self.b))
     }
 
+    #[must_use]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.a
+            .total_cmp(&other.a)
+            .then_with(|| self.b.total_cmp(&other.b))
+    }
+
     #[must_use]
     pub const fn flip(&self) -> Self {
         Self::new(self.b, self.a)
@@ -36,4 +63,226 @@ impl<const N: usize, T: Float> Edge<N, T> {
     pub fn length_squared(&self) -> T {
         self.a.distance_squared(self.b)
     }
+
+    #[must_use]
+    pub fn midpoint(&self) -> Point<N, T> {
+        (self.a + self.b) / T::from(2.0)
+    }
+
+    #[must_use]
+    pub fn vector(&self) -> Point<N, T> {
+        self.b - self.a
+    }
+
+    #[must_use]
+    pub fn direction(&self) -> Point<N, T> {
+        self.vector().normalize()
+    }
+
+    #[must_use]
+    pub fn point_at(&self, t: T) -> Point<N, T> {
+        self.a + self.vector() * t
+    }
+
+    #[must_use]
+    pub fn projection_parameter(&self, p: Point<N, T>) -> T {
+        let len_sq = self.length_squared();
+        if len_sq == T::zero() {
+            return T::zero();
+        }
+        (p - self.a).dot(self.vector()) / len_sq
+    }
+
+    #[must_use]
+    pub fn closest_point(&self, p: Point<N, T>) -> Point<N, T> {
+        let t = self.projection_parameter(p).max(T::zero()).min(T::one());
+        self.point_at(t)
+    }
+
+    #[must_use]
+    pub fn distance_squared_to_point(&self, p: Point<N, T>) -> T {
+        self.closest_point(p).distance_squared(p)
+    }
+
+    #[must_use]
+    pub fn distance_to_point(&self, p: Point<N, T>) -> T {
+        self.distance_squared_to_point(p).sqrt()
+    }
+}
+
+impl<T: Float> Edge2<T> {
+    #[must_use]
+    pub fn orientation(&self, p: Point2<T>) -> T {
+        self.vector().cross(p - self.a)
+    }
+
+    #[must_use]
+    pub fn side(&self, p: Point2<T>) -> Side {
+        let orientation = self.orientation(p);
+        if orientation > T::zero() {
+            Side::Left
+        } else if orientation < T::zero() {
+            Side::Right
+        } else {
+            Side::On
+        }
+    }
+
+    #[must_use]
+    pub fn contains_point(&self, p: Point2<T>) -> bool {
+        self.side(p) == Side::On
+            && p.x >= self.a.x.min(self.b.x)
+            && p.x <= self.a.x.max(self.b.x)
+            && p.y >= self.a.y.min(self.b.y)
+            && p.y <= self.a.y.max(self.b.y)
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> SegmentIntersection<T> {
+        if self.a == self.b || other.a == other.b {
+            let (point, segment) = if self.a == self.b {
+                (self.a, other)
+            } else {
+                (other.a, self)
+            };
+            return if segment.contains_point(point) {
+                SegmentIntersection::Point(point)
+            } else {
+                SegmentIntersection::None
+            };
+        }
+        let sides = [
+            self.side(other.a),
+            self.side(other.b),
+            other.side(self.a),
+            other.side(self.b),
+        ];
+        if sides[0] == Side::On && sides[1] == Side::On {
+            return self.collinear_overlap(other);
+        }
+        if sides[0] == sides[1] || sides[2] == sides[3] {
+            return SegmentIntersection::None;
+        }
+        let endpoints = [
+            (sides[0], other.a),
+            (sides[1], other.b),
+            (sides[2], self.a),
+            (sides[3], self.b),
+        ];
+        if let Some(&(_, p)) = endpoints.iter().find(|(side, _)| *side == Side::On) {
+            return SegmentIntersection::Point(p);
+        }
+        let t = (other.a - self.a).cross(other.vector()) / self.vector().cross(other.vector());
+        SegmentIntersection::Point(self.point_at(t))
+    }
+
+    fn collinear_overlap(&self, other: &Self) -> SegmentIntersection<T> {
+        let t0 = self.projection_parameter(other.a);
+        let t1 = self.projection_parameter(other.b);
+        let (p0, t0, p1, t1) = if t0 <= t1 {
+            (other.a, t0, other.b, t1)
+        } else {
+            (other.b, t1, other.a, t0)
+        };
+        let (start, t_start) = if t0 > T::zero() {
+            (p0, t0)
+        } else {
+            (self.a, T::zero())
+        };
+        let (end, t_end) = if t1 < T::one() {
+            (p1, t1)
+        } else {
+            (self.b, T::one())
+        };
+        if t_start > t_end {
+            SegmentIntersection::None
+        } else if start == end {
+            SegmentIntersection::Point(start)
+        } else {
+            SegmentIntersection::Overlap(Self::new(start, end))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point3;
+
+    fn edge(a: [f64; 2], b: [f64; 2]) -> Edge2<f64> {
+        Edge2::new(Point2::from(a), Point2::from(b))
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let e = Edge3::new(Point3::from([0.0, 0.0, 0.0]), Point3::from([2.0, 0.0, 0.0]));
+        assert_eq!(e.midpoint(), Point3::from([1.0, 0.0, 0.0]));
+        assert_eq!(e.direction(), Point3::from([1.0, 0.0, 0.0]));
+        let p = Point3::from([3.0, 1.0, 0.0]);
+        assert_eq!(e.projection_parameter(p), 1.5);
+        assert_eq!(e.closest_point(p), Point3::from([2.0, 0.0, 0.0]));
+        assert_eq!(e.distance_squared_to_point(p), 2.0);
+        let p = Point3::from([0.5, 0.0, 2.0]);
+        assert_eq!(e.distance_to_point(p), 2.0);
+    }
+
+    #[test]
+    fn test_side() {
+        let e = edge([0.0, 0.0], [2.0, 0.0]);
+        assert_eq!(e.side(Point2::from([1.0, 1.0])), Side::Left);
+        assert_eq!(e.side(Point2::from([1.0, -1.0])), Side::Right);
+        assert_eq!(e.side(Point2::from([5.0, 0.0])), Side::On);
+        assert!(!e.contains_point(Point2::from([5.0, 0.0])));
+        assert!(e.contains_point(Point2::from([2.0, 0.0])));
+    }
+
+    #[test]
+    fn test_intersection_point() {
+        let e = edge([0.0, 0.0], [2.0, 2.0]);
+        assert_eq!(
+            e.intersection(&edge([0.0, 2.0], [2.0, 0.0])),
+            SegmentIntersection::Point(Point2::from([1.0, 1.0]))
+        );
+        assert_eq!(
+            e.intersection(&edge([2.0, 2.0], [3.0, 0.0])),
+            SegmentIntersection::Point(Point2::from([2.0, 2.0]))
+        );
+        assert_eq!(
+            e.intersection(&edge([1.0, 1.0], [3.0, 0.0])),
+            SegmentIntersection::Point(Point2::from([1.0, 1.0]))
+        );
+        assert_eq!(
+            e.intersection(&edge([3.0, 0.0], [4.0, 1.0])),
+            SegmentIntersection::None
+        );
+        assert_eq!(
+            e.intersection(&edge([1.0, 0.0], [3.0, 2.0])),
+            SegmentIntersection::None
+        );
+    }
+
+    #[test]
+    fn test_intersection_collinear() {
+        let e = edge([0.0, 0.0], [4.0, 0.0]);
+        assert_eq!(
+            e.intersection(&edge([5.0, 0.0], [3.0, 0.0])),
+            SegmentIntersection::Overlap(edge([3.0, 0.0], [4.0, 0.0]))
+        );
+        assert_eq!(
+            e.intersection(&edge([1.0, 0.0], [2.0, 0.0])),
+            SegmentIntersection::Overlap(edge([1.0, 0.0], [2.0, 0.0]))
+        );
+        assert_eq!(
+            e.intersection(&edge([4.0, 0.0], [6.0, 0.0])),
+            SegmentIntersection::Point(Point2::from([4.0, 0.0]))
+        );
+        assert_eq!(
+            e.intersection(&edge([5.0, 0.0], [6.0, 0.0])),
+            SegmentIntersection::None
+        );
+        assert_eq!(
+            e.intersection(&edge([2.0, 0.0], [2.0, 0.0])),
+            SegmentIntersection::Point(Point2::from([2.0, 0.0]))
+        );
+    }
 }
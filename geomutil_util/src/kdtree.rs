@@ -0,0 +1,273 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::{bounding_box::BoundingBox, point::Point, scalar::Float};
+
+struct Neighbour<T: Float> {
+    distance_squared: T,
+    index: usize,
+}
+
+impl<T: Float> PartialEq for Neighbour<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Float> Eq for Neighbour<T> {}
+
+impl<T: Float> PartialOrd for Neighbour<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for Neighbour<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance_squared
+            .total_cmp(&other.distance_squared)
+            .then_with(|| self.index.cmp(&other.index))
+    }
+}
+
+pub struct KdTree<const N: usize, T: Float> {
+    points: Vec<Point<N, T>>,
+    indices: Vec<usize>,
+}
+
+impl<const N: usize, T: Float> KdTree<N, T> {
+    pub fn new(points: impl IntoIterator<Item = Point<N, T>>) -> Self {
+        let points = points.into_iter().collect::<Vec<_>>();
+        let mut indices = (0..points.len()).collect::<Vec<_>>();
+        Self::build(&points, &mut indices, 0);
+        Self { points, indices }
+    }
+
+    fn build(points: &[Point<N, T>], indices: &mut [usize], depth: usize) {
+        if indices.len() <= 1 {
+            return;
+        }
+        let axis = depth % N;
+        let mid = indices.len() / 2;
+        indices.select_nth_unstable_by(mid, |&a, &b| points[a][axis].total_cmp(&points[b][axis]));
+        let (left, right) = indices.split_at_mut(mid);
+        Self::build(points, left, depth + 1);
+        Self::build(points, &mut right[1..], depth + 1);
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    #[must_use]
+    pub fn points(&self) -> &[Point<N, T>] {
+        &self.points
+    }
+
+    #[must_use]
+    pub fn nearest(&self, p: Point<N, T>) -> Option<usize> {
+        self.k_nearest(p, 1).first().copied()
+    }
+
+    #[must_use]
+    pub fn k_nearest(&self, p: Point<N, T>, k: usize) -> Vec<usize> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        self.search_nearest(&self.indices, 0, p, k, &mut heap);
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|n| n.index)
+            .collect()
+    }
+
+    fn search_nearest(
+        &self,
+        indices: &[usize],
+        depth: usize,
+        p: Point<N, T>,
+        k: usize,
+        heap: &mut BinaryHeap<Neighbour<T>>,
+    ) {
+        if indices.is_empty() {
+            return;
+        }
+        let axis = depth % N;
+        let mid = indices.len() / 2;
+        let index = indices[mid];
+        let point = self.points[index];
+        heap.push(Neighbour {
+            distance_squared: point.distance_squared(p),
+            index,
+        });
+        if heap.len() > k {
+            heap.pop();
+        }
+        let diff = p[axis] - point[axis];
+        let (near, far) = if diff < T::zero() {
+            (&indices[..mid], &indices[mid + 1..])
+        } else {
+            (&indices[mid + 1..], &indices[..mid])
+        };
+        self.search_nearest(near, depth + 1, p, k, heap);
+        let is_full = heap.len() == k;
+        if !is_full
+            || heap
+                .peek()
+                .is_some_and(|n| diff * diff <= n.distance_squared)
+        {
+            self.search_nearest(far, depth + 1, p, k, heap);
+        }
+    }
+
+    #[must_use]
+    pub fn within_box(&self, bbox: &BoundingBox<N, T>) -> Vec<usize> {
+        let mut result = Vec::new();
+        self.search_box(&self.indices, 0, bbox, &mut result);
+        result
+    }
+
+    fn search_box(
+        &self,
+        indices: &[usize],
+        depth: usize,
+        bbox: &BoundingBox<N, T>,
+        result: &mut Vec<usize>,
+    ) {
+        if indices.is_empty() {
+            return;
+        }
+        let axis = depth % N;
+        let mid = indices.len() / 2;
+        let index = indices[mid];
+        let point = self.points[index];
+        if bbox.contains(point) {
+            result.push(index);
+        }
+        if bbox.lower[axis] <= point[axis] {
+            self.search_box(&indices[..mid], depth + 1, bbox, result);
+        }
+        if bbox.upper[axis] >= point[axis] {
+            self.search_box(&indices[mid + 1..], depth + 1, bbox, result);
+        }
+    }
+
+    #[must_use]
+    pub fn within_radius(&self, p: Point<N, T>, radius: T) -> Vec<usize> {
+        let mut result = Vec::new();
+        self.search_radius(&self.indices, 0, p, radius * radius, &mut result);
+        result
+    }
+
+    fn search_radius(
+        &self,
+        indices: &[usize],
+        depth: usize,
+        p: Point<N, T>,
+        radius_squared: T,
+        result: &mut Vec<usize>,
+    ) {
+        if indices.is_empty() {
+            return;
+        }
+        let axis = depth % N;
+        let mid = indices.len() / 2;
+        let index = indices[mid];
+        let point = self.points[index];
+        if point.distance_squared(p) <= radius_squared {
+            result.push(index);
+        }
+        let diff = p[axis] - point[axis];
+        let (near, far) = if diff < T::zero() {
+            (&indices[..mid], &indices[mid + 1..])
+        } else {
+            (&indices[mid + 1..], &indices[..mid])
+        };
+        self.search_radius(near, depth + 1, p, radius_squared, result);
+        if diff * diff <= radius_squared {
+            self.search_radius(far, depth + 1, p, radius_squared, result);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        point::{Point2, Point3},
+        test_utils::random_unit,
+    };
+
+    fn random_points(n: usize) -> Vec<Point3<f64>> {
+        let mut next = random_unit(42);
+        (0..n)
+            .map(|_| Point3::from([next(), next(), next()]))
+            .collect()
+    }
+
+    fn brute_force_knn(points: &[Point3<f64>], p: Point3<f64>, k: usize) -> Vec<usize> {
+        let mut indices = (0..points.len()).collect::<Vec<_>>();
+        indices.sort_by(|&a, &b| {
+            points[a]
+                .distance_squared(p)
+                .total_cmp(&points[b].distance_squared(p))
+                .then_with(|| a.cmp(&b))
+        });
+        indices.truncate(k);
+        indices
+    }
+
+    #[test]
+    fn test_small() {
+        let tree = KdTree::new([
+            Point2::from([0.0, 0.0]),
+            Point2::from([5.0, 5.0]),
+            Point2::from([1.0, 1.0]),
+            Point2::from([9.0, 0.0]),
+        ]);
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.nearest(Point2::from([4.0, 4.0])), Some(1));
+        assert_eq!(tree.k_nearest(Point2::from([0.2, 0.2]), 2), [0, 2]);
+        let bbox = BoundingBox::new(Point2::from([0.0, 0.0]), Point2::from([5.0, 5.0]));
+        let mut inside = tree.within_box(&bbox);
+        inside.sort_unstable();
+        assert_eq!(inside, [0, 1, 2]);
+        let mut near = tree.within_radius(Point2::from([0.0, 0.0]), 1.5);
+        near.sort_unstable();
+        assert_eq!(near, [0, 2]);
+
+        let empty = KdTree::<2, f64>::new([]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.nearest(Point2::zero()), None);
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        let points = random_points(2000);
+        let tree = KdTree::new(points.iter().copied());
+        for &q in &random_points(50) {
+            assert_eq!(tree.k_nearest(q, 7), brute_force_knn(&points, q, 7));
+
+            let mut found = tree.within_radius(q, 0.1);
+            found.sort_unstable();
+            let expected = (0..points.len())
+                .filter(|&i| points[i].distance(q) <= 0.1)
+                .collect::<Vec<_>>();
+            assert_eq!(found, expected);
+
+            let bbox = BoundingBox::new(q - 0.1, q + 0.15);
+            let mut found = tree.within_box(&bbox);
+            found.sort_unstable();
+            let expected = (0..points.len())
+                .filter(|&i| bbox.contains(points[i]))
+                .collect::<Vec<_>>();
+            assert_eq!(found, expected);
+        }
+    }
+}
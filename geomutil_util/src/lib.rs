@@ -1,13 +1,39 @@
+mod boolean;
 mod bounding_box;
 mod edge;
+mod kdtree;
+mod offset;
 mod point;
+mod polygon;
+mod quaternion;
+mod rtree;
 mod scalar;
 mod shape2;
+mod simplify;
+mod sweep;
+#[cfg(test)]
+mod test_utils;
+mod transform;
 mod triangle;
+mod triangle3;
 
+pub use boolean::{BooleanOp, boolean_op, union_all};
 pub use bounding_box::{BoundingBox2, BoundingBox3};
-pub use edge::{Edge2, Edge3};
+pub use edge::{Edge2, Edge3, SegmentIntersection, Side};
+pub use kdtree::KdTree;
+pub use offset::{EndCap, JoinStyle, buffer_polyline, offset_polygons};
 pub use point::{Point2, Point3};
+pub use polygon::{
+    Location, Polygon, assign_to_regions, locate_in_ring, ring_signed_area, winding_number,
+};
+pub use quaternion::Quaternion;
+pub use rtree::RTree;
 pub use scalar::Float;
 pub use shape2::Shape2D;
+pub use simplify::{
+    simplify_douglas_peucker, simplify_rings_preserving_topology, simplify_visvalingam,
+};
+pub use sweep::segment_intersections;
+pub use transform::{Transform2, Transform3};
 pub use triangle::Triangle;
+pub use triangle3::{Plane, Triangle3};
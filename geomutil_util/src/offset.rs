@@ -0,0 +1,345 @@
+use crate::{
+    boolean::{BooleanOp, boolean_op, union_all},
+    point::Point2,
+    polygon::{Polygon, cross, ring_edges},
+    scalar::Float,
+};
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinStyle<T: Float> {
+    Miter(T),
+    Round(T),
+    Bevel,
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EndCap<T: Float> {
+    Butt,
+    Square,
+    Round(T),
+}
+
+const MIN_ARC_TOLERANCE: f64 = 1e-6;
+
+fn right_normal<T: Float>(a: Point2<T>, b: Point2<T>) -> Point2<T> {
+    let d = (b - a).normalize();
+    Point2::from([d.y, T::zero() - d.x])
+}
+
+fn arc<T: Float>(
+    center: Point2<T>,
+    from: Point2<T>,
+    sweep: T,
+    distance: T,
+    tolerance: T,
+) -> Vec<Point2<T>> {
+    let tolerance = tolerance
+        .max(distance * T::from(MIN_ARC_TOLERANCE))
+        .min(distance);
+    let step = T::from(2.0) * (T::one() - tolerance / distance).acos();
+    let start = from.y.atan2(from.x);
+    let mut points = Vec::new();
+    let mut i = T::one();
+    while i * step < sweep.abs() {
+        let angle = if sweep > T::zero() {
+            start + i * step
+        } else {
+            start - i * step
+        };
+        points.push(center + Point2::from([angle.cos(), angle.sin()]) * distance);
+        i += T::one();
+    }
+    points
+}
+
+fn join<T: Float>(
+    [a, v, b]: [Point2<T>; 3],
+    normals: [Point2<T>; 2],
+    distance: T,
+    style: JoinStyle<T>,
+) -> Option<Polygon<T>> {
+    let turn = cross(a, v, b);
+    if turn == T::zero() {
+        return None;
+    }
+    let sign = if turn > T::zero() {
+        T::one()
+    } else {
+        T::zero() - T::one()
+    };
+    let [n1, n2] = normals.map(|n| n * sign);
+    let p1 = v + n1 * distance;
+    let p2 = v + n2 * distance;
+    let mut ring = vec![v, p1];
+    match style {
+        JoinStyle::Bevel => {}
+        JoinStyle::Miter(limit) => {
+            let bisector = n1 + n2;
+            let len_sq = bisector.length_squared();
+            if len_sq > T::zero() && T::from(2.0) / len_sq.sqrt() <= limit {
+                ring.push(v + bisector * (T::from(2.0) * distance / len_sq));
+            }
+        }
+        JoinStyle::Round(tolerance) => {
+            let sweep = (n1.x * n2.y - n1.y * n2.x).atan2(n1.dot(n2));
+            ring.extend(arc(v, n1, sweep, distance, tolerance));
+        }
+    }
+    ring.push(p2);
+    Some(Polygon::new(ring, Vec::new()))
+}
+
+fn cap<T: Float>(
+    end: Point2<T>,
+    normal: Point2<T>,
+    distance: T,
+    style: EndCap<T>,
+) -> Option<Polygon<T>> {
+    let p1 = end + normal * distance;
+    let p2 = end - normal * distance;
+    let outward = Point2::from([T::zero() - normal.y, normal.x]) * distance;
+    match style {
+        EndCap::Butt => None,
+        EndCap::Square => Some(Polygon::new(
+            vec![p1, p1 + outward, p2 + outward, p2],
+            Vec::new(),
+        )),
+        EndCap::Round(tolerance) => {
+            let mut ring = vec![p1];
+            ring.extend(arc(end, normal, T::PI, distance, tolerance));
+            ring.push(p2);
+            Some(Polygon::new(ring, Vec::new()))
+        }
+    }
+}
+
+fn edge_rect<T: Float>(a: Point2<T>, b: Point2<T>, n: Point2<T>, distance: T) -> Polygon<T> {
+    Polygon::new(
+        vec![
+            a + n * distance,
+            b + n * distance,
+            b - n * distance,
+            a - n * distance,
+        ],
+        Vec::new(),
+    )
+}
+
+fn dedup_path<T: Float>(points: &[Point2<T>]) -> Vec<Point2<T>> {
+    let mut path = points.to_vec();
+    path.dedup();
+    path
+}
+
+fn ring_buffer<T: Float>(ring: &[Point2<T>], distance: T, style: JoinStyle<T>) -> Vec<Polygon<T>> {
+    let mut ring = dedup_path(ring);
+    if ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    let n = ring.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    let normals = ring_edges(&ring)
+        .map(|(a, b)| right_normal(a, b))
+        .collect::<Vec<_>>();
+    let mut pieces = ring_edges(&ring)
+        .zip(&normals)
+        .map(|((a, b), &normal)| edge_rect(a, b, normal, distance))
+        .collect::<Vec<_>>();
+    pieces.extend((0..n).filter_map(|i| {
+        let prev = (i + n - 1) % n;
+        join(
+            [ring[prev], ring[i], ring[(i + 1) % n]],
+            [normals[prev], normals[i]],
+            distance,
+            style,
+        )
+    }));
+    pieces
+}
+
+#[must_use]
+pub fn offset_polygons<T: Float>(
+    polygons: &[Polygon<T>],
+    delta: T,
+    style: JoinStyle<T>,
+) -> Vec<Polygon<T>> {
+    if delta == T::zero() {
+        return polygons.to_vec();
+    }
+    let distance = delta.abs();
+    let buffer = union_all(
+        polygons
+            .iter()
+            .flat_map(Polygon::rings)
+            .flat_map(|ring| ring_buffer(ring, distance, style)),
+    );
+    let op = if delta > T::zero() {
+        BooleanOp::Union
+    } else {
+        BooleanOp::Difference
+    };
+    boolean_op(polygons, &buffer, op)
+}
+
+#[must_use]
+pub fn buffer_polyline<T: Float>(
+    line: &[Point2<T>],
+    distance: T,
+    style: JoinStyle<T>,
+    end_cap: EndCap<T>,
+) -> Vec<Polygon<T>> {
+    let line = dedup_path(line);
+    if line.len() < 2 || distance <= T::zero() {
+        return Vec::new();
+    }
+    let normals = line
+        .windows(2)
+        .map(|w| right_normal(w[0], w[1]))
+        .collect::<Vec<_>>();
+    let mut pieces = line
+        .windows(2)
+        .zip(&normals)
+        .map(|(w, &normal)| edge_rect(w[0], w[1], normal, distance))
+        .collect::<Vec<_>>();
+    pieces.extend(
+        line.windows(3)
+            .zip(normals.windows(2))
+            .filter_map(|(w, n)| join([w[0], w[1], w[2]], [n[0], n[1]], distance, style)),
+    );
+    let last = normals.len() - 1;
+    pieces.extend(cap(line[0], Point2::zero() - normals[0], distance, end_cap));
+    pieces.extend(cap(line[line.len() - 1], normals[last], distance, end_cap));
+    union_all(pieces)
+}
+
+impl<T: Float> Polygon<T> {
+    #[must_use]
+    pub fn offset(&self, delta: T, style: JoinStyle<T>) -> Vec<Self> {
+        offset_polygons(std::slice::from_ref(self), delta, style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x0: f64, y0: f64, x1: f64, y1: f64) -> Polygon<f64> {
+        Polygon::new(
+            vec![
+                Point2::from([x0, y0]),
+                Point2::from([x1, y0]),
+                Point2::from([x1, y1]),
+                Point2::from([x0, y1]),
+            ],
+            Vec::new(),
+        )
+    }
+
+    fn total_area(polygons: &[Polygon<f64>]) -> f64 {
+        polygons.iter().map(Polygon::area).sum()
+    }
+
+    fn assert_approx_eq_eps(a: f64, b: f64, eps: f64) {
+        assert!((a - b).abs() < eps, "wanted: {b}, got: {a}");
+    }
+
+    #[test]
+    fn test_inflate_joins() {
+        let square = rect(0.0, 0.0, 2.0, 2.0);
+
+        let miter = square.offset(1.0, JoinStyle::Miter(2.0));
+        assert_eq!(miter.len(), 1);
+        assert_eq!(miter[0].exterior.len(), 4);
+        assert_approx_eq_eps(total_area(&miter), 16.0, 1e-9);
+
+        let bevel = square.offset(1.0, JoinStyle::Bevel);
+        assert_eq!(bevel[0].exterior.len(), 8);
+        assert_approx_eq_eps(total_area(&bevel), 14.0, 1e-9);
+
+        let limited = square.offset(1.0, JoinStyle::Miter(1.2));
+        assert_approx_eq_eps(total_area(&limited), 14.0, 1e-9);
+
+        let round = square.offset(1.0, JoinStyle::Round(1e-3));
+        assert_approx_eq_eps(total_area(&round), 12.0 + std::f64::consts::PI, 1e-2);
+    }
+
+    #[test]
+    fn test_deflate() {
+        let square = rect(0.0, 0.0, 4.0, 4.0);
+        let shrunk = square.offset(-1.0, JoinStyle::Miter(2.0));
+        assert_eq!(shrunk.len(), 1);
+        assert_approx_eq_eps(total_area(&shrunk), 4.0, 1e-9);
+
+        assert!(square.offset(-2.5, JoinStyle::Round(1e-3)).is_empty());
+    }
+
+    #[test]
+    fn test_deflate_splits() {
+        let dumbbell = boolean_op(
+            &[rect(0.0, 0.0, 4.0, 4.0), rect(6.0, 0.0, 10.0, 4.0)],
+            &[rect(3.0, 1.5, 7.0, 2.5)],
+            BooleanOp::Union,
+        );
+        assert_eq!(dumbbell.len(), 1);
+        let shrunk = offset_polygons(&dumbbell, -1.0, JoinStyle::Bevel);
+        assert_eq!(shrunk.len(), 2);
+        assert_approx_eq_eps(total_area(&shrunk), 8.5, 1e-9);
+    }
+
+    #[test]
+    fn test_inflate_closes_hole() {
+        let holed = Polygon::new(
+            rect(0.0, 0.0, 6.0, 6.0).exterior,
+            vec![rect(2.0, 2.0, 4.0, 4.0).exterior],
+        );
+        let grown = holed.offset(0.5, JoinStyle::Miter(2.0));
+        assert_eq!(grown[0].holes.len(), 1);
+        assert_approx_eq_eps(total_area(&grown), 49.0 - 1.0, 1e-9);
+        let filled = holed.offset(1.5, JoinStyle::Miter(2.0));
+        assert!(filled[0].holes.is_empty());
+    }
+
+    #[test]
+    fn test_arc_degenerate_tolerance() {
+        let center = Point2::from([1.0, 2.0]);
+        let from = Point2::from([1.0, 0.0]);
+        let reference = arc(center, from, std::f64::consts::PI, 2.0, 2e-6);
+        for tolerance in [0.0, -1.0, f64::NAN] {
+            let points = arc(center, from, std::f64::consts::PI, 2.0, tolerance);
+            assert!(!points.is_empty() && points.len() <= reference.len());
+            for p in points {
+                assert_approx_eq_eps(p.distance(center), 2.0, 1e-9);
+            }
+        }
+
+        let line = [Point2::from([0.0, 0.0]), Point2::from([4.0, 0.0])];
+        let round = buffer_polyline(&line, 1.0, JoinStyle::Round(0.0), EndCap::Round(-1.0));
+        assert_approx_eq_eps(total_area(&round), 8.0 + std::f64::consts::PI, 1e-5);
+    }
+
+    #[test]
+    fn test_buffer_polyline() {
+        let line = [
+            Point2::from([0.0, 0.0]),
+            Point2::from([4.0, 0.0]),
+            Point2::from([4.0, 4.0]),
+        ];
+        let butt = buffer_polyline(&line, 1.0, JoinStyle::Miter(2.0), EndCap::Butt);
+        assert_eq!(butt.len(), 1);
+        assert_approx_eq_eps(total_area(&butt), 16.0, 1e-9);
+
+        let square = buffer_polyline(&line, 1.0, JoinStyle::Miter(2.0), EndCap::Square);
+        assert_approx_eq_eps(total_area(&square), 20.0, 1e-9);
+
+        let round = buffer_polyline(&line, 1.0, JoinStyle::Round(1e-3), EndCap::Round(1e-3));
+        assert_approx_eq_eps(
+            total_area(&round),
+            1.25f64.mul_add(std::f64::consts::PI, 15.0),
+            1e-2,
+        );
+    }
+}
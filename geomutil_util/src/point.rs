@@ -278,6 +278,33 @@ impl<const N: usize, T: Float> Point<N, T> {
         if len > T::zero() { self / len } else { self }
     }
 
+    pub fn angle(self, other: Self) -> T {
+        let u = self * other.length();
+        let v = other * self.length();
+        T::from(2.0) * (u - v).length().atan2((u + v).length())
+    }
+
+    pub fn project_onto(self, other: Self) -> Self {
+        let len_sq = other.length_squared();
+        if len_sq > T::zero() {
+            other * (self.dot(other) / len_sq)
+        } else {
+            Self::zero()
+        }
+    }
+
+    pub fn reject_from(self, other: Self) -> Self {
+        self - self.project_onto(other)
+    }
+
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        self + (other - self) * t
+    }
+
+    pub fn reflect(self, normal: Self) -> Self {
+        self - self.project_onto(normal) * T::from(2.0)
+    }
+
     pub fn unique(points: impl IntoIterator<Item = Self>) -> Vec<Self> {
         let mut points = points.into_iter().collect::<Vec<_>>();
         points.sort_by(|a, b| a.total_cmp(b));
@@ -361,6 +388,18 @@ impl<const N: usize, T: Float> IndexMut<usize> for Point<N, T> {
 }
 
 impl<T: Float> Point2<T> {
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn perp(self) -> Self {
+        Self::from([T::zero() - self.y, self.x])
+    }
+
+    pub fn signed_angle(self, other: Self) -> T {
+        self.cross(other).atan2(self.dot(other))
+    }
+
     pub fn polar_angle(self) -> T {
         let angle = self.y.atan2(self.x);
         if angle >= T::zero() {
@@ -371,6 +410,16 @@ impl<T: Float> Point2<T> {
     }
 }
 
+impl<T: Float> Point3<T> {
+    pub fn cross(self, other: Self) -> Self {
+        Self::from([
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        ])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -578,4 +627,57 @@ mod tests {
             EPS,
         );
     }
+
+    #[test]
+    fn test_cross() {
+        let u = Point::from([2.0, 0.0]);
+        let v = Point::from([1.0, 3.0]);
+        assert_approx_eq_eps(u.cross(v), 6.0, EPS);
+        assert_approx_eq_eps(v.cross(u), -6.0, EPS);
+        assert_eq!(u.perp(), Point::from([0.0, 2.0]));
+        assert_approx_eq_eps(u.perp().dot(u), 0.0, EPS);
+
+        let x = Point::from([1.0, 0.0, 0.0]);
+        let y = Point::from([0.0, 1.0, 0.0]);
+        assert_eq!(x.cross(y), Point::from([0.0, 0.0, 1.0]));
+        assert_eq!(y.cross(x), Point::from([0.0, 0.0, -1.0]));
+    }
+
+    #[test]
+    fn test_angle() {
+        let u = Point::from([1.0, 0.0]);
+        let v = Point::from([1.0, 1.0]);
+        assert_approx_eq_eps(u.angle(v).to_degrees(), 45.0, EPS);
+        assert_approx_eq_eps(u.angle(u * 3.0), 0.0, EPS);
+        assert_approx_eq_eps(u.angle(u * -1.0).to_degrees(), 180.0, EPS);
+        assert_approx_eq_eps(u.angle(Point::zero()), 0.0, EPS);
+        let tiny = Point::from([1.0, 1e-10]);
+        assert!((u.angle(tiny) / 1e-10 - 1.0).abs() < 1e-12);
+        let (pi, near_pi) = (std::f64::consts::PI, u.angle(tiny * -1.0));
+        assert!((pi - near_pi - 1e-10).abs() < 1e-15);
+        let a = Point::from([1.0, 2.0, 3.0]);
+        let expected = 13.0f64.sqrt() / 14.0 * 1e-9;
+        assert!((a.angle(a + Point::from([1e-9, 0.0, 0.0])) / expected - 1.0).abs() < 1e-6);
+        assert_approx_eq_eps(u.signed_angle(v).to_degrees(), 45.0, EPS);
+        assert_approx_eq_eps(v.signed_angle(u).to_degrees(), -45.0, EPS);
+    }
+
+    #[test]
+    fn test_projection() {
+        let v = Point::from([3.0, 4.0, 5.0]);
+        let axis = Point::from([2.0, 0.0, 0.0]);
+        assert_eq!(v.project_onto(axis), Point::from([3.0, 0.0, 0.0]));
+        assert_eq!(v.reject_from(axis), Point::from([0.0, 4.0, 5.0]));
+        assert_eq!(v.project_onto(Point::zero()), Point::zero());
+    }
+
+    #[test]
+    fn test_lerp_and_reflect() {
+        let a = Point::from([0.0, 0.0]);
+        let b = Point::from([4.0, 2.0]);
+        assert_eq!(a.lerp(b, 0.25), Point::from([1.0, 0.5]));
+        assert_eq!(a.lerp(b, 1.0), b);
+        let v = Point::from([1.0, -1.0]);
+        assert_eq!(v.reflect(Point::from([0.0, 2.0])), Point::from([1.0, 1.0]));
+    }
 }
@@ -0,0 +1,264 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{point::Point2, scalar::Float};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    Inside,
+    Outside,
+    Boundary,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Polygon<T: Float> {
+    pub exterior: Vec<Point2<T>>,
+    pub holes: Vec<Vec<Point2<T>>>,
+}
+
+pub(crate) fn cross<T: Float>(o: Point2<T>, a: Point2<T>, b: Point2<T>) -> T {
+    (a - o).cross(b - o)
+}
+
+pub(crate) fn is_on_segment<T: Float>(p: Point2<T>, a: Point2<T>, b: Point2<T>) -> bool {
+    cross(a, b, p) == T::zero()
+        && p.x >= a.x.min(b.x)
+        && p.x <= a.x.max(b.x)
+        && p.y >= a.y.min(b.y)
+        && p.y <= a.y.max(b.y)
+}
+
+fn segments_cross<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>, d: Point2<T>) -> bool {
+    let zero = T::zero();
+    let d1 = cross(a, b, c);
+    let d2 = cross(a, b, d);
+    let d3 = cross(c, d, a);
+    let d4 = cross(c, d, b);
+    ((d1 > zero && d2 < zero) || (d1 < zero && d2 > zero))
+        && ((d3 > zero && d4 < zero) || (d3 < zero && d4 > zero))
+}
+
+pub(crate) fn ring_edges<T: Float>(
+    ring: &[Point2<T>],
+) -> impl Iterator<Item = (Point2<T>, Point2<T>)> + '_ {
+    ring.iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+#[must_use]
+pub fn winding_number<T: Float>(ring: &[Point2<T>], p: Point2<T>) -> i32 {
+    let zero = T::zero();
+    ring_edges(ring).fold(0, |wn, (a, b)| {
+        if a.y <= p.y {
+            if b.y > p.y && cross(a, b, p) > zero {
+                return wn + 1;
+            }
+        } else if b.y <= p.y && cross(a, b, p) < zero {
+            return wn - 1;
+        }
+        wn
+    })
+}
+
+#[must_use]
+pub fn locate_in_ring<T: Float>(ring: &[Point2<T>], p: Point2<T>) -> Location {
+    if ring_edges(ring).any(|(a, b)| is_on_segment(p, a, b)) {
+        Location::Boundary
+    } else if winding_number(ring, p) == 0 {
+        Location::Outside
+    } else {
+        Location::Inside
+    }
+}
+
+#[must_use]
+pub fn ring_signed_area<T: Float>(ring: &[Point2<T>]) -> T {
+    T::from(0.5)
+        * ring_edges(ring)
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<T>()
+}
+
+impl<T: Float> Polygon<T> {
+    #[must_use]
+    pub const fn new(exterior: Vec<Point2<T>>, holes: Vec<Vec<Point2<T>>>) -> Self {
+        Self { exterior, holes }
+    }
+
+    pub fn rings(&self) -> impl Iterator<Item = &[Point2<T>]> {
+        std::iter::once(self.exterior.as_slice()).chain(self.holes.iter().map(Vec::as_slice))
+    }
+
+    #[must_use]
+    pub fn area(&self) -> T {
+        let holes: T = self.holes.iter().map(|h| ring_signed_area(h).abs()).sum();
+        ring_signed_area(&self.exterior).abs() - holes
+    }
+
+    #[must_use]
+    pub fn locate(&self, p: Point2<T>) -> Location {
+        match locate_in_ring(&self.exterior, p) {
+            Location::Inside => {}
+            location => return location,
+        }
+        for hole in &self.holes {
+            match locate_in_ring(hole, p) {
+                Location::Outside => {}
+                Location::Inside => return Location::Outside,
+                Location::Boundary => return Location::Boundary,
+            }
+        }
+        Location::Inside
+    }
+
+    pub fn locate_all(&self, points: impl IntoIterator<Item = Point2<T>>) -> Vec<Location> {
+        points.into_iter().map(|p| self.locate(p)).collect()
+    }
+
+    #[must_use]
+    pub fn contains(&self, p: Point2<T>) -> bool {
+        self.locate(p) != Location::Outside
+    }
+
+    #[must_use]
+    pub fn contains_polygon(&self, other: &Self) -> bool {
+        if !other.exterior.iter().all(|&p| self.contains(p)) {
+            return false;
+        }
+        let crosses = ring_edges(&other.exterior).any(|(a, b)| {
+            self.rings()
+                .flat_map(ring_edges)
+                .any(|(c, d)| segments_cross(a, b, c, d))
+        });
+        if crosses {
+            return false;
+        }
+        let midpoints_inside =
+            ring_edges(&other.exterior).all(|(a, b)| self.contains((a + b) / T::from(2.0)));
+        if !midpoints_inside {
+            return false;
+        }
+        self.holes
+            .iter()
+            .flatten()
+            .all(|&p| other.locate(p) != Location::Inside)
+    }
+}
+
+#[must_use]
+pub fn assign_to_regions<T: Float>(
+    regions: &[Polygon<T>],
+    points: impl IntoIterator<Item = Point2<T>>,
+) -> Vec<Option<usize>> {
+    points
+        .into_iter()
+        .map(|p| regions.iter().position(|region| region.contains(p)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(lo: f64, hi: f64) -> Vec<Point2<f64>> {
+        vec![
+            Point2::from([lo, lo]),
+            Point2::from([hi, lo]),
+            Point2::from([hi, hi]),
+            Point2::from([lo, hi]),
+        ]
+    }
+
+    #[test]
+    fn test_winding_number() {
+        let ring = square(0.0, 2.0);
+        assert_eq!(winding_number(&ring, Point2::from([1.0, 1.0])), 1);
+        assert_eq!(winding_number(&ring, Point2::from([3.0, 1.0])), 0);
+        let reversed = ring.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(winding_number(&reversed, Point2::from([1.0, 1.0])), -1);
+    }
+
+    #[test]
+    fn test_locate_in_ring() {
+        let ring = square(0.0, 2.0);
+        assert_eq!(
+            locate_in_ring(&ring, Point2::from([1.0, 1.0])),
+            Location::Inside
+        );
+        assert_eq!(
+            locate_in_ring(&ring, Point2::from([2.0, 1.0])),
+            Location::Boundary
+        );
+        assert_eq!(
+            locate_in_ring(&ring, Point2::from([0.0, 0.0])),
+            Location::Boundary
+        );
+        assert_eq!(
+            locate_in_ring(&ring, Point2::from([-1.0, 1.0])),
+            Location::Outside
+        );
+        assert_eq!(
+            locate_in_ring(&ring, Point2::from([1.0, 2.5])),
+            Location::Outside
+        );
+    }
+
+    #[test]
+    fn test_polygon_with_hole() {
+        let polygon = Polygon::new(square(0.0, 4.0), vec![square(1.0, 3.0)]);
+        assert_approx_eq(polygon.area(), 12.0);
+        let locations = polygon.locate_all([
+            Point2::from([0.5, 0.5]),
+            Point2::from([2.0, 2.0]),
+            Point2::from([1.0, 2.0]),
+            Point2::from([5.0, 5.0]),
+        ]);
+        assert_eq!(
+            locations,
+            [
+                Location::Inside,
+                Location::Outside,
+                Location::Boundary,
+                Location::Outside
+            ]
+        );
+    }
+
+    #[test]
+    fn test_contains_polygon() {
+        let outer = Polygon::new(square(0.0, 4.0), vec![]);
+        let inner = Polygon::new(square(1.0, 3.0), vec![]);
+        assert!(outer.contains_polygon(&inner));
+        assert!(!inner.contains_polygon(&outer));
+        assert!(outer.contains_polygon(&outer));
+
+        let holed = Polygon::new(square(0.0, 4.0), vec![square(1.5, 2.5)]);
+        assert!(!holed.contains_polygon(&inner));
+        let ring = Polygon::new(square(1.0, 3.0), vec![square(1.5, 2.5)]);
+        assert!(holed.contains_polygon(&ring));
+
+        let shifted = Polygon::new(square(3.0, 5.0), vec![]);
+        assert!(!outer.contains_polygon(&shifted));
+    }
+
+    #[test]
+    fn test_assign_to_regions() {
+        let regions = [
+            Polygon::new(square(0.0, 1.0), vec![]),
+            Polygon::new(square(2.0, 3.0), vec![]),
+        ];
+        let assigned = assign_to_regions(
+            &regions,
+            [
+                Point2::from([0.5, 0.5]),
+                Point2::from([2.5, 2.5]),
+                Point2::from([1.5, 1.5]),
+            ],
+        );
+        assert_eq!(assigned, [Some(0), Some(1), None]);
+    }
+
+    fn assert_approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-10, "wanted: {b}, got: {a}");
+    }
+}
@@ -0,0 +1,310 @@
+use std::ops::Mul;
+
+use crate::{point::Point3, scalar::Float, transform::Transform3};
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion<T: Float> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Float> Default for Quaternion<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Float> Mul for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+impl<T: Float> Quaternion<T> {
+    #[must_use]
+    pub const fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self { w, x, y, z }
+    }
+
+    #[must_use]
+    pub fn identity() -> Self {
+        Self::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+
+    #[must_use]
+    pub fn from_axis_angle(axis: Point3<T>, angle: T) -> Self {
+        let half = T::from(0.5) * angle;
+        let v = axis.normalize() * half.sin();
+        Self::new(half.cos(), v.x, v.y, v.z)
+    }
+
+    #[must_use]
+    pub fn to_axis_angle(&self) -> (Point3<T>, T) {
+        let q = self.normalize();
+        let v = q.vector();
+        let sin = v.length();
+        if sin == T::zero() {
+            return (Point3::from([T::one(), T::zero(), T::zero()]), T::zero());
+        }
+        (v / sin, T::from(2.0) * sin.atan2(q.w))
+    }
+
+    #[must_use]
+    pub fn from_euler(roll: T, pitch: T, yaw: T) -> Self {
+        let x = Point3::from([T::one(), T::zero(), T::zero()]);
+        let y = Point3::from([T::zero(), T::one(), T::zero()]);
+        let z = Point3::from([T::zero(), T::zero(), T::one()]);
+        Self::from_axis_angle(z, yaw)
+            * Self::from_axis_angle(y, pitch)
+            * Self::from_axis_angle(x, roll)
+    }
+
+    #[must_use]
+    pub fn to_euler(&self) -> [T; 3] {
+        let Self { w, x, y, z } = self.normalize();
+        let two = T::from(2.0);
+        let roll = (two * (w * x + y * z)).atan2(T::one() - two * (x * x + y * y));
+        let sin_pitch = (two * (w * y - z * x))
+            .max(T::zero() - T::one())
+            .min(T::one());
+        let yaw = (two * (w * z + x * y)).atan2(T::one() - two * (y * y + z * z));
+        [roll, sin_pitch.asin(), yaw]
+    }
+
+    #[must_use]
+    pub fn vector(&self) -> Point3<T> {
+        Point3::from([self.x, self.y, self.z])
+    }
+
+    #[must_use]
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.vector().dot(other.vector())
+    }
+
+    #[must_use]
+    pub fn norm(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    #[must_use]
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        if norm > T::zero() {
+            self.scale(T::one() / norm)
+        } else {
+            *self
+        }
+    }
+
+    #[must_use]
+    pub fn conjugate(&self) -> Self {
+        let zero = T::zero();
+        Self::new(self.w, zero - self.x, zero - self.y, zero - self.z)
+    }
+
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        let norm_sq = self.dot(self);
+        (norm_sq > T::zero()).then(|| self.conjugate().scale(T::one() / norm_sq))
+    }
+
+    fn scale(&self, factor: T) -> Self {
+        Self::new(
+            self.w * factor,
+            self.x * factor,
+            self.y * factor,
+            self.z * factor,
+        )
+    }
+
+    #[must_use]
+    pub fn rotate(&self, p: Point3<T>) -> Point3<T> {
+        let q = self.normalize();
+        let v = q.vector();
+        let t = v.cross(p) * T::from(2.0);
+        p + t * q.w + v.cross(t)
+    }
+
+    #[must_use]
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let a = self.normalize();
+        let mut b = other.normalize();
+        let mut cos = a.dot(&b);
+        if cos < T::zero() {
+            b = b.scale(T::zero() - T::one());
+            cos = T::zero() - cos;
+        }
+        let (wa, wb) = if cos > T::one() - T::from(1e-6) {
+            (T::one() - t, t)
+        } else {
+            let theta = cos.acos();
+            let sin = theta.sin();
+            (
+                ((T::one() - t) * theta).sin() / sin,
+                (t * theta).sin() / sin,
+            )
+        };
+        Self::new(
+            a.w * wa + b.w * wb,
+            a.x * wa + b.x * wb,
+            a.y * wa + b.y * wb,
+            a.z * wa + b.z * wb,
+        )
+        .normalize()
+    }
+
+    #[must_use]
+    pub fn to_rotation_matrix(&self) -> [[T; 3]; 3] {
+        let Self { w, x, y, z } = self.normalize();
+        let one = T::one();
+        let two = T::from(2.0);
+        [
+            [
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        ]
+    }
+
+    #[must_use]
+    pub fn from_rotation_matrix(m: [[T; 3]; 3]) -> Self {
+        let one = T::one();
+        let quarter = T::from(0.25);
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let q = if trace > T::zero() {
+            let s = (trace + one).sqrt() * T::from(2.0);
+            Self::new(
+                quarter * s,
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * T::from(2.0);
+            Self::new(
+                (m[2][1] - m[1][2]) / s,
+                quarter * s,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * T::from(2.0);
+            Self::new(
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                quarter * s,
+                (m[1][2] + m[2][1]) / s,
+            )
+        } else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * T::from(2.0);
+            Self::new(
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                quarter * s,
+            )
+        };
+        q.normalize()
+    }
+}
+
+impl<T: Float> From<Quaternion<T>> for Transform3<T> {
+    fn from(q: Quaternion<T>) -> Self {
+        let mut t = Self::identity();
+        for (row, values) in t.matrix.iter_mut().zip(q.to_rotation_matrix()) {
+            row[..3].copy_from_slice(&values);
+        }
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use super::*;
+
+    fn assert_approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-10, "wanted: {b}, got: {a}");
+    }
+
+    fn assert_point_approx_eq(a: Point3<f64>, b: Point3<f64>) {
+        assert!(a.distance(b) < 1e-10, "wanted: {b:?}, got: {a:?}");
+    }
+
+    fn z_axis() -> Point3<f64> {
+        Point3::from([0.0, 0.0, 1.0])
+    }
+
+    #[test]
+    fn test_axis_angle_rotation() {
+        let q = Quaternion::from_axis_angle(z_axis(), FRAC_PI_2);
+        let p = Point3::from([1.0, 0.0, 0.0]);
+        assert_point_approx_eq(q.rotate(p), Point3::from([0.0, 1.0, 0.0]));
+        let (axis, angle) = q.to_axis_angle();
+        assert_point_approx_eq(axis, z_axis());
+        assert_approx_eq(angle, FRAC_PI_2);
+        assert_point_approx_eq((q * q).rotate(p), Point3::from([-1.0, 0.0, 0.0]));
+        assert_point_approx_eq(q.inverse().unwrap().rotate(q.rotate(p)), p);
+        assert_eq!(Quaternion::<f64>::identity().to_axis_angle().1, 0.0);
+    }
+
+    #[test]
+    fn test_euler() {
+        let q = Quaternion::from_euler(0.1, -0.4, 2.0);
+        let [roll, pitch, yaw] = q.to_euler();
+        assert_approx_eq(roll, 0.1);
+        assert_approx_eq(pitch, -0.4);
+        assert_approx_eq(yaw, 2.0);
+        let yaw_only = Quaternion::from_euler(0.0, 0.0, FRAC_PI_2);
+        let expected = Quaternion::from_axis_angle(z_axis(), FRAC_PI_2);
+        assert_approx_eq(yaw_only.dot(&expected), 1.0);
+    }
+
+    #[test]
+    fn test_slerp() {
+        let a = Quaternion::identity();
+        let b = Quaternion::from_axis_angle(z_axis(), FRAC_PI_2);
+        let half = a.slerp(&b, 0.5);
+        assert_approx_eq(half.to_axis_angle().1, FRAC_PI_4);
+        assert_approx_eq(a.slerp(&b, 0.0).dot(&a), 1.0);
+        assert_approx_eq(a.slerp(&b, 1.0).dot(&b), 1.0);
+    }
+
+    #[test]
+    fn test_rotation_matrix() {
+        for q in [
+            Quaternion::from_axis_angle(Point3::from([1.0, 2.0, 3.0]), 0.7),
+            Quaternion::from_axis_angle(Point3::from([1.0, 0.0, 0.0]), PI),
+            Quaternion::from_axis_angle(Point3::from([0.0, 1.0, 0.0]), PI),
+            Quaternion::from_axis_angle(z_axis(), PI),
+        ] {
+            let back = Quaternion::from_rotation_matrix(q.to_rotation_matrix());
+            assert_approx_eq(back.dot(&q).abs(), 1.0);
+            let p = Point3::from([0.3, -1.0, 2.0]);
+            let t = Transform3::from(q);
+            assert_point_approx_eq(t.apply_point(p), q.rotate(p));
+        }
+    }
+}
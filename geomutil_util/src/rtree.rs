@@ -0,0 +1,600 @@
+use std::{cmp::Ordering, collections::BinaryHeap, mem, slice};
+
+use crate::{bounding_box::BoundingBox, point::Point, scalar::Float};
+
+const MAX_CHILDREN: usize = 16;
+const MIN_CHILDREN: usize = 4;
+
+struct Entry<const N: usize, T: Float, V> {
+    bbox: BoundingBox<N, T>,
+    value: V,
+}
+
+enum Children<const N: usize, T: Float, V> {
+    Leaf(Vec<Entry<N, T, V>>),
+    Internal(Vec<Node<N, T, V>>),
+}
+
+struct Node<const N: usize, T: Float, V> {
+    bbox: BoundingBox<N, T>,
+    children: Children<N, T, V>,
+}
+
+fn union_all<const N: usize, T: Float>(
+    boxes: impl IntoIterator<Item = BoundingBox<N, T>>,
+) -> Option<BoundingBox<N, T>> {
+    boxes.into_iter().reduce(|a, b| a.union(&b))
+}
+
+fn chunks<I>(items: Vec<I>, size: usize) -> Vec<Vec<I>> {
+    let mut chunks = Vec::with_capacity(items.len().div_ceil(size));
+    let mut items = items.into_iter().peekable();
+    while items.peek().is_some() {
+        chunks.push(items.by_ref().take(size).collect());
+    }
+    chunks
+}
+
+fn sort_by_center<const N: usize, T: Float, I>(
+    items: &mut [I],
+    bbox: impl Fn(&I) -> BoundingBox<N, T>,
+    axis: usize,
+) {
+    items.sort_by(|a, b| {
+        let a = bbox(a);
+        let b = bbox(b);
+        (a.lower[axis] + a.upper[axis]).total_cmp(&(b.lower[axis] + b.upper[axis]))
+    });
+}
+
+fn str_pack<const N: usize, T: Float, I>(
+    mut items: Vec<I>,
+    bbox: impl Fn(&I) -> BoundingBox<N, T> + Copy,
+    axis: usize,
+) -> Vec<Vec<I>> {
+    let groups = items.len().div_ceil(MAX_CHILDREN);
+    if groups <= 1 {
+        return vec![items];
+    }
+    sort_by_center(&mut items, bbox, axis);
+    if axis + 1 >= N {
+        return chunks(items, MAX_CHILDREN);
+    }
+    let dims = u32::try_from(N - axis).unwrap_or(u32::MAX);
+    let slabs = (1..=groups)
+        .find(|s: &usize| s.pow(dims) >= groups)
+        .unwrap_or(groups);
+    let slab_size = items.len().div_ceil(slabs);
+    chunks(items, slab_size)
+        .into_iter()
+        .flat_map(|slab| str_pack(slab, bbox, axis + 1))
+        .collect()
+}
+
+fn split_half<const N: usize, T: Float, I>(
+    mut items: Vec<I>,
+    bbox: impl Fn(&I) -> BoundingBox<N, T> + Copy,
+) -> (Vec<I>, Vec<I>) {
+    let extent = union_all(items.iter().map(|item| {
+        let b = bbox(item);
+        BoundingBox {
+            lower: b.lower + b.upper,
+            upper: b.lower + b.upper,
+        }
+    }));
+    let axis = extent.map_or(0, |extent| {
+        let dimensions = extent.dimensions();
+        (0..N)
+            .max_by(|&i, &j| dimensions[i].total_cmp(&dimensions[j]))
+            .unwrap_or(0)
+    });
+    sort_by_center(&mut items, bbox, axis);
+    let right = items.split_off(items.len() / 2);
+    (items, right)
+}
+
+impl<const N: usize, T: Float, V> Node<N, T, V> {
+    fn leaf(entries: Vec<Entry<N, T, V>>) -> Self {
+        let mut node = Self {
+            bbox: entries[0].bbox,
+            children: Children::Leaf(entries),
+        };
+        node.update_bbox();
+        node
+    }
+
+    fn internal(nodes: Vec<Self>) -> Self {
+        let mut node = Self {
+            bbox: nodes[0].bbox,
+            children: Children::Internal(nodes),
+        };
+        node.update_bbox();
+        node
+    }
+
+    const fn len(&self) -> usize {
+        match &self.children {
+            Children::Leaf(entries) => entries.len(),
+            Children::Internal(nodes) => nodes.len(),
+        }
+    }
+
+    fn update_bbox(&mut self) {
+        let bbox = match &self.children {
+            Children::Leaf(entries) => union_all(entries.iter().map(|e| e.bbox)),
+            Children::Internal(nodes) => union_all(nodes.iter().map(|n| n.bbox)),
+        };
+        if let Some(bbox) = bbox {
+            self.bbox = bbox;
+        }
+    }
+
+    fn split(&mut self) -> Self {
+        match &mut self.children {
+            Children::Leaf(entries) => {
+                let (left, right) = split_half(mem::take(entries), |e| e.bbox);
+                *entries = left;
+                self.update_bbox();
+                Self::leaf(right)
+            }
+            Children::Internal(nodes) => {
+                let (left, right) = split_half(mem::take(nodes), |n| n.bbox);
+                *nodes = left;
+                self.update_bbox();
+                Self::internal(right)
+            }
+        }
+    }
+
+    fn insert(&mut self, entry: Entry<N, T, V>) -> Option<Self> {
+        self.bbox = self.bbox.union(&entry.bbox);
+        let is_overflow = match &mut self.children {
+            Children::Leaf(entries) => {
+                entries.push(entry);
+                entries.len() > MAX_CHILDREN
+            }
+            Children::Internal(nodes) => {
+                let enlargement = |n: &Self| n.bbox.union(&entry.bbox).volume() - n.bbox.volume();
+                let best = (0..nodes.len())
+                    .min_by(|&i, &j| {
+                        enlargement(&nodes[i])
+                            .total_cmp(&enlargement(&nodes[j]))
+                            .then_with(|| nodes[i].bbox.volume().total_cmp(&nodes[j].bbox.volume()))
+                    })
+                    .unwrap_or(0);
+                if let Some(sibling) = nodes[best].insert(entry) {
+                    nodes.push(sibling);
+                }
+                nodes.len() > MAX_CHILDREN
+            }
+        };
+        is_overflow.then(|| self.split())
+    }
+
+    fn remove(
+        &mut self,
+        bbox: &BoundingBox<N, T>,
+        value: &V,
+        orphans: &mut Vec<Entry<N, T, V>>,
+    ) -> Option<V>
+    where
+        V: PartialEq,
+    {
+        let removed = match &mut self.children {
+            Children::Leaf(entries) => {
+                let i = entries
+                    .iter()
+                    .position(|e| e.bbox == *bbox && e.value == *value)?;
+                entries.swap_remove(i).value
+            }
+            Children::Internal(nodes) => {
+                let (i, removed) = nodes.iter_mut().enumerate().find_map(|(i, n)| {
+                    if n.bbox.contains_box(bbox) {
+                        n.remove(bbox, value, orphans).map(|v| (i, v))
+                    } else {
+                        None
+                    }
+                })?;
+                if nodes[i].len() < MIN_CHILDREN {
+                    nodes.swap_remove(i).into_entries(orphans);
+                }
+                removed
+            }
+        };
+        self.update_bbox();
+        Some(removed)
+    }
+
+    fn into_entries(self, entries: &mut Vec<Entry<N, T, V>>) {
+        match self.children {
+            Children::Leaf(leaf) => entries.extend(leaf),
+            Children::Internal(nodes) => {
+                for node in nodes {
+                    node.into_entries(entries);
+                }
+            }
+        }
+    }
+}
+
+pub struct RTree<const N: usize, T: Float, V> {
+    root: Option<Node<N, T, V>>,
+    len: usize,
+}
+
+impl<const N: usize, T: Float, V> Default for RTree<N, T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, T: Float, V> FromIterator<(BoundingBox<N, T>, V)> for RTree<N, T, V> {
+    fn from_iter<I: IntoIterator<Item = (BoundingBox<N, T>, V)>>(iter: I) -> Self {
+        Self::bulk_load(iter)
+    }
+}
+
+impl<const N: usize, T: Float, V> RTree<N, T, V> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    pub fn bulk_load(items: impl IntoIterator<Item = (BoundingBox<N, T>, V)>) -> Self {
+        let entries = items
+            .into_iter()
+            .map(|(bbox, value)| Entry { bbox, value })
+            .collect::<Vec<_>>();
+        let len = entries.len();
+        if entries.is_empty() {
+            return Self::new();
+        }
+        let mut nodes = str_pack(entries, |e| e.bbox, 0)
+            .into_iter()
+            .map(Node::leaf)
+            .collect::<Vec<_>>();
+        while nodes.len() > 1 {
+            nodes = str_pack(nodes, |n| n.bbox, 0)
+                .into_iter()
+                .map(Node::internal)
+                .collect();
+        }
+        Self {
+            root: nodes.pop(),
+            len,
+        }
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[must_use]
+    pub fn bounding_box(&self) -> Option<BoundingBox<N, T>> {
+        self.root.as_ref().map(|root| root.bbox)
+    }
+
+    pub fn insert(&mut self, bbox: BoundingBox<N, T>, value: V) {
+        let entry = Entry { bbox, value };
+        self.len += 1;
+        let Some(root) = &mut self.root else {
+            self.root = Some(Node::leaf(vec![entry]));
+            return;
+        };
+        if let Some(sibling) = root.insert(entry)
+            && let Some(root) = self.root.take()
+        {
+            self.root = Some(Node::internal(vec![root, sibling]));
+        }
+    }
+
+    pub fn remove(&mut self, bbox: &BoundingBox<N, T>, value: &V) -> Option<V>
+    where
+        V: PartialEq,
+    {
+        let mut orphans = Vec::new();
+        let removed = self.root.as_mut()?.remove(bbox, value, &mut orphans)?;
+        self.len -= 1;
+        while let Some(root) = self.root.take() {
+            match root.children {
+                Children::Internal(mut nodes) if nodes.len() <= 1 => self.root = nodes.pop(),
+                Children::Leaf(entries) if entries.is_empty() => {}
+                children => {
+                    self.root = Some(Node {
+                        bbox: root.bbox,
+                        children,
+                    });
+                    break;
+                }
+            }
+        }
+        self.len -= orphans.len();
+        for Entry { bbox, value } in orphans {
+            self.insert(bbox, value);
+        }
+        Some(removed)
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, N, T, V> {
+        Iter::new(self.root.as_ref(), None)
+    }
+
+    #[must_use]
+    pub fn intersecting(&self, bbox: &BoundingBox<N, T>) -> Iter<'_, N, T, V> {
+        Iter::new(self.root.as_ref(), Some(*bbox))
+    }
+
+    /// Returns the entry with the smallest `distance` from `p`, together with that distance.
+    ///
+    /// Nodes are ranked by `bbox.distance_to_point(p)`, so `distance(bbox, value)` must never
+    /// be less than that box distance. A callback that breaks this can miss closer entries.
+    pub fn nearest(
+        &self,
+        p: Point<N, T>,
+        distance: impl Fn(&BoundingBox<N, T>, &V) -> T,
+    ) -> Option<(&V, T)> {
+        let mut heap = BinaryHeap::new();
+        if let Some(root) = &self.root {
+            heap.push(Queued {
+                distance: root.bbox.distance_to_point(p),
+                item: QueuedItem::Node(root),
+            });
+        }
+        while let Some(Queued { distance: d, item }) = heap.pop() {
+            match item {
+                QueuedItem::Entry(entry) => return Some((&entry.value, d)),
+                QueuedItem::Node(node) => match &node.children {
+                    Children::Leaf(entries) => heap.extend(entries.iter().map(|e| Queued {
+                        distance: distance(&e.bbox, &e.value),
+                        item: QueuedItem::Entry(e),
+                    })),
+                    Children::Internal(nodes) => heap.extend(nodes.iter().map(|n| Queued {
+                        distance: n.bbox.distance_to_point(p),
+                        item: QueuedItem::Node(n),
+                    })),
+                },
+            }
+        }
+        None
+    }
+}
+
+impl<'a, const N: usize, T: Float, V> IntoIterator for &'a RTree<N, T, V> {
+    type Item = (&'a BoundingBox<N, T>, &'a V);
+    type IntoIter = Iter<'a, N, T, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+enum QueuedItem<'a, const N: usize, T: Float, V> {
+    Node(&'a Node<N, T, V>),
+    Entry(&'a Entry<N, T, V>),
+}
+
+struct Queued<'a, const N: usize, T: Float, V> {
+    distance: T,
+    item: QueuedItem<'a, N, T, V>,
+}
+
+impl<const N: usize, T: Float, V> PartialEq for Queued<'_, N, T, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<const N: usize, T: Float, V> Eq for Queued<'_, N, T, V> {}
+
+impl<const N: usize, T: Float, V> PartialOrd for Queued<'_, N, T, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize, T: Float, V> Ord for Queued<'_, N, T, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let is_entry = |q: &Self| matches!(q.item, QueuedItem::Entry(_));
+        other
+            .distance
+            .total_cmp(&self.distance)
+            .then_with(|| is_entry(self).cmp(&is_entry(other)))
+    }
+}
+
+pub struct Iter<'a, const N: usize, T: Float, V> {
+    stack: Vec<&'a Node<N, T, V>>,
+    entries: slice::Iter<'a, Entry<N, T, V>>,
+    filter: Option<BoundingBox<N, T>>,
+}
+
+impl<'a, const N: usize, T: Float, V> Iter<'a, N, T, V> {
+    fn new(root: Option<&'a Node<N, T, V>>, filter: Option<BoundingBox<N, T>>) -> Self {
+        let mut iter = Self {
+            stack: Vec::new(),
+            entries: [].iter(),
+            filter,
+        };
+        iter.stack
+            .extend(root.filter(|root| iter.is_selected(&root.bbox)));
+        iter
+    }
+
+    fn is_selected(&self, bbox: &BoundingBox<N, T>) -> bool {
+        self.filter.is_none_or(|filter| filter.overlaps(bbox))
+    }
+}
+
+impl<'a, const N: usize, T: Float, V> Iterator for Iter<'a, N, T, V> {
+    type Item = (&'a BoundingBox<N, T>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let filter = self.filter;
+            if let Some(entry) = self
+                .entries
+                .by_ref()
+                .find(|e| filter.is_none_or(|filter| filter.overlaps(&e.bbox)))
+            {
+                return Some((&entry.bbox, &entry.value));
+            }
+            let node = self.stack.pop()?;
+            match &node.children {
+                Children::Leaf(entries) => self.entries = entries.iter(),
+                Children::Internal(nodes) => {
+                    for n in nodes {
+                        if self.is_selected(&n.bbox) {
+                            self.stack.push(n);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{point::Point2, test_utils::random_unit};
+
+    fn random_boxes(n: usize) -> Vec<BoundingBox<2, f64>> {
+        let mut next = random_unit(7);
+        (0..n)
+            .map(|_| {
+                let p = Point2::from([next() * 100.0, next() * 100.0]);
+                let size = Point2::from([next() * 3.0, next() * 3.0]);
+                BoundingBox::new(p, p + size)
+            })
+            .collect()
+    }
+
+    fn sorted(iter: impl Iterator<Item = usize>) -> Vec<usize> {
+        let mut values = iter.collect::<Vec<_>>();
+        values.sort_unstable();
+        values
+    }
+
+    fn check_against_brute_force(
+        tree: &RTree<2, f64, usize>,
+        boxes: &[Option<BoundingBox<2, f64>>],
+    ) {
+        let live = boxes.iter().flatten().count();
+        assert_eq!(tree.len(), live);
+        assert_eq!(tree.iter().count(), live);
+        for query in random_boxes(30) {
+            let query = query.expand_by(5.0);
+            let found = sorted(tree.intersecting(&query).map(|(_, &i)| i));
+            let expected = (0..boxes.len())
+                .filter(|&i| boxes[i].is_some_and(|b| b.overlaps(&query)))
+                .collect::<Vec<_>>();
+            assert_eq!(found, expected);
+
+            let p = query.center();
+            let (&nearest, distance) = tree.nearest(p, |b, _| b.distance_to_point(p)).unwrap();
+            let expected = boxes
+                .iter()
+                .flatten()
+                .map(|b| b.distance_to_point(p))
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(distance, expected);
+            assert_eq!(boxes[nearest].unwrap().distance_to_point(p), expected);
+        }
+    }
+
+    #[test]
+    fn test_bulk_load() {
+        let boxes = random_boxes(1000);
+        let tree = boxes
+            .iter()
+            .copied()
+            .zip(0..)
+            .collect::<RTree<2, f64, usize>>();
+        check_against_brute_force(&tree, &boxes.into_iter().map(Some).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_insert_remove() {
+        let boxes = random_boxes(600);
+        let mut tree = RTree::new();
+        for (i, &b) in boxes.iter().enumerate() {
+            tree.insert(b, i);
+        }
+        let mut live = boxes.into_iter().map(Some).collect::<Vec<_>>();
+        check_against_brute_force(&tree, &live);
+
+        for i in (0..live.len()).step_by(3) {
+            let b = live[i].take().unwrap();
+            assert_eq!(tree.remove(&b, &i), Some(i));
+            assert_eq!(tree.remove(&b, &i), None);
+        }
+        check_against_brute_force(&tree, &live);
+
+        for (i, b) in live.iter_mut().enumerate() {
+            if let Some(b) = b.take() {
+                assert_eq!(tree.remove(&b, &i), Some(i));
+            }
+        }
+        assert!(tree.is_empty());
+        assert!(tree.bounding_box().is_none());
+        assert_eq!(tree.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_nearest_with_callback() {
+        let points = [
+            Point2::from([0.0, 0.0]),
+            Point2::from([10.0, 0.0]),
+            Point2::from([5.0, 5.0]),
+        ];
+        let tree = RTree::bulk_load(points.iter().map(|&p| (BoundingBox::new(p, p), p)));
+        let (&p, distance) = tree
+            .nearest(Point2::from([6.0, 4.0]), |_, p| {
+                p.distance(Point2::from([6.0, 4.0]))
+            })
+            .unwrap();
+        assert_eq!(p, points[2]);
+        assert!((distance - 2.0f64.sqrt()).abs() < 1e-12);
+        assert!(
+            RTree::<2, f64, ()>::new()
+                .nearest(Point2::zero(), |_, ()| 0.0)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_nearest_requires_lower_bounded_callback() {
+        let boxes = random_boxes(1000);
+        let tree = boxes
+            .iter()
+            .copied()
+            .zip(0..)
+            .collect::<RTree<2, f64, usize>>();
+        let p = Point2::from([1.0, 1.0]);
+        let by_distance = |i: &usize, j: &usize| {
+            boxes[*i]
+                .distance_to_point(p)
+                .total_cmp(&boxes[*j].distance_to_point(p))
+        };
+        let closest = (0..boxes.len()).min_by(by_distance).unwrap();
+        let farthest = (0..boxes.len()).max_by(by_distance).unwrap();
+        let (&found, _) = tree
+            .nearest(p, |b, &i| {
+                if i == farthest {
+                    0.0
+                } else {
+                    b.distance_to_point(p)
+                }
+            })
+            .unwrap();
+        assert_eq!(found, closest);
+        assert_ne!(found, farthest);
+    }
+}
@@ -29,6 +29,7 @@ pub trait Float:
 {
     const PI: Self;
     const E: Self;
+    const EPSILON: Self;
     const NAN: Self;
 
     #[must_use]
@@ -82,6 +83,7 @@ macro_rules! impl_float {
         impl Float for $t {
             const PI: Self = std::$t::consts::PI;
             const E: Self = std::$t::consts::E;
+            const EPSILON: Self = $t::EPSILON;
             const NAN: Self = $t::NAN;
             fn abs(self) -> Self {
                 self.abs()
@@ -0,0 +1,370 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::{
+    edge::Edge,
+    point::{Point, Point2},
+    polygon::{Polygon, cross},
+    scalar::Float,
+};
+
+struct Candidate<T: Float> {
+    area: T,
+    ring: usize,
+    index: usize,
+}
+
+impl<T: Float> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Float> Eq for Candidate<T> {}
+
+impl<T: Float> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .total_cmp(&self.area)
+            .then_with(|| other.ring.cmp(&self.ring))
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+fn triangle_area<const N: usize, T: Float>(a: Point<N, T>, b: Point<N, T>, c: Point<N, T>) -> T {
+    let ab = b - a;
+    let ac = c - a;
+    let area_sq = ab.length_squared() * ac.length_squared() - ab.dot(ac).powi(2);
+    T::from(0.5) * area_sq.max(T::zero()).sqrt()
+}
+
+#[must_use]
+pub fn simplify_douglas_peucker<const N: usize, T: Float>(
+    points: &[Point<N, T>],
+    tolerance: T,
+) -> Vec<Point<N, T>> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let tolerance_sq = tolerance * tolerance;
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let farthest = (first + 1..last)
+            .map(|i| {
+                let d = Edge::new(points[first], points[last]).distance_squared_to_point(points[i]);
+                (i, d)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((i, d)) = farthest
+            && d > tolerance_sq
+        {
+            keep[i] = true;
+            stack.push((first, i));
+            stack.push((i, last));
+        }
+    }
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(&p, keep)| keep.then_some(p))
+        .collect()
+}
+
+struct Rings<const N: usize, T: Float> {
+    points: Vec<Vec<Point<N, T>>>,
+    prev: Vec<Vec<usize>>,
+    next: Vec<Vec<usize>>,
+    removed: Vec<Vec<bool>>,
+    len: Vec<usize>,
+    closed: bool,
+}
+
+impl<const N: usize, T: Float> Rings<N, T> {
+    fn new(rings: &[Vec<Point<N, T>>], closed: bool) -> Self {
+        let points = rings.to_vec();
+        let prev = points
+            .iter()
+            .map(|r| (0..r.len()).map(|i| (i + r.len() - 1) % r.len()).collect())
+            .collect();
+        let next = points
+            .iter()
+            .map(|r| (0..r.len()).map(|i| (i + 1) % r.len()).collect())
+            .collect();
+        let removed = points.iter().map(|r| vec![false; r.len()]).collect();
+        let len = points.iter().map(Vec::len).collect();
+        Self {
+            points,
+            prev,
+            next,
+            removed,
+            len,
+            closed,
+        }
+    }
+
+    fn is_removable(&self, ring: usize, i: usize) -> bool {
+        let n = self.points[ring].len();
+        let min_len = if self.closed { 3 } else { 2 };
+        !self.removed[ring][i]
+            && self.len[ring] > min_len
+            && (self.closed || (i != 0 && i != n - 1))
+    }
+
+    fn area(&self, ring: usize, i: usize) -> T {
+        let points = &self.points[ring];
+        triangle_area(
+            points[self.prev[ring][i]],
+            points[i],
+            points[self.next[ring][i]],
+        )
+    }
+
+    fn remove(&mut self, ring: usize, i: usize) {
+        let prev = self.prev[ring][i];
+        let next = self.next[ring][i];
+        self.next[ring][prev] = next;
+        self.prev[ring][next] = prev;
+        self.removed[ring][i] = true;
+        self.len[ring] -= 1;
+    }
+
+    fn candidates(&self) -> BinaryHeap<Candidate<T>> {
+        let mut heap = BinaryHeap::new();
+        for ring in 0..self.points.len() {
+            for index in 0..self.points[ring].len() {
+                if self.is_removable(ring, index) {
+                    let area = self.area(ring, index);
+                    heap.push(Candidate { area, ring, index });
+                }
+            }
+        }
+        heap
+    }
+
+    fn into_points(self) -> Vec<Vec<Point<N, T>>> {
+        self.points
+            .into_iter()
+            .zip(self.removed)
+            .map(|(points, removed)| {
+                points
+                    .into_iter()
+                    .zip(removed)
+                    .filter_map(|(p, removed)| (!removed).then_some(p))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn simplify(mut self, min_area: T, is_allowed: impl Fn(&Self, usize, usize) -> bool) -> Self {
+        let mut heap = self.candidates();
+        while let Some(Candidate { area, ring, index }) = heap.pop() {
+            if area >= min_area {
+                break;
+            }
+            if !self.is_removable(ring, index) || self.area(ring, index) != area {
+                continue;
+            }
+            if !is_allowed(&self, ring, index) {
+                continue;
+            }
+            let prev = self.prev[ring][index];
+            let next = self.next[ring][index];
+            self.remove(ring, index);
+            for neighbour in [prev, next] {
+                if self.is_removable(ring, neighbour) {
+                    let area = self.area(ring, neighbour);
+                    heap.push(Candidate {
+                        area,
+                        ring,
+                        index: neighbour,
+                    });
+                }
+            }
+        }
+        self
+    }
+}
+
+#[must_use]
+pub fn simplify_visvalingam<const N: usize, T: Float>(
+    points: &[Point<N, T>],
+    min_area: T,
+) -> Vec<Point<N, T>> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    Rings::new(&[points.to_vec()], false)
+        .simplify(min_area, |_, _, _| true)
+        .into_points()
+        .swap_remove(0)
+}
+
+fn segments_intersect<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>, d: Point2<T>) -> bool {
+    let zero = T::zero();
+    let d1 = cross(a, b, c);
+    let d2 = cross(a, b, d);
+    let d3 = cross(c, d, a);
+    let d4 = cross(c, d, b);
+    if d1 == zero && d2 == zero {
+        return overlaps(a, b, c, d);
+    }
+    d1 * d2 <= zero && d3 * d4 <= zero
+}
+
+fn overlaps<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>, d: Point2<T>) -> bool {
+    a.x.max(b.x) >= c.x.min(d.x)
+        && c.x.max(d.x) >= a.x.min(b.x)
+        && a.y.max(b.y) >= c.y.min(d.y)
+        && c.y.max(d.y) >= a.y.min(b.y)
+}
+
+fn is_in_triangle<T: Float>(p: Point2<T>, a: Point2<T>, b: Point2<T>, c: Point2<T>) -> bool {
+    let zero = T::zero();
+    let d1 = cross(a, b, p);
+    let d2 = cross(b, c, p);
+    let d3 = cross(c, a, p);
+    let has_neg = d1 < zero || d2 < zero || d3 < zero;
+    let has_pos = d1 > zero || d2 > zero || d3 > zero;
+    !(has_neg && has_pos)
+}
+
+fn is_removal_safe<T: Float>(rings: &Rings<2, T>, ring: usize, index: usize) -> bool {
+    let prev = rings.prev[ring][index];
+    let next = rings.next[ring][index];
+    let [a, v, b] = [prev, index, next].map(|j| rings.points[ring][j]);
+    for (r, points) in rings.points.iter().enumerate() {
+        for j in 0..points.len() {
+            if rings.removed[r][j] {
+                continue;
+            }
+            let k = rings.next[r][j];
+            let is_own = |x: usize| r == ring && (x == prev || x == index || x == next);
+            if !is_own(j) && is_in_triangle(points[j], a, v, b) {
+                return false;
+            }
+            if is_own(j) || is_own(k) {
+                continue;
+            }
+            if segments_intersect(a, b, points[j], points[k]) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+#[must_use]
+pub fn simplify_rings_preserving_topology<T: Float>(
+    rings: &[Vec<Point2<T>>],
+    min_area: T,
+) -> Vec<Vec<Point2<T>>> {
+    Rings::new(rings, true)
+        .simplify(min_area, is_removal_safe)
+        .into_points()
+}
+
+impl<T: Float> Polygon<T> {
+    #[must_use]
+    pub fn simplify(&self, min_area: T) -> Self {
+        let rings = self.rings().map(<[_]>::to_vec).collect::<Vec<_>>();
+        let mut rings = simplify_rings_preserving_topology(&rings, min_area).into_iter();
+        let exterior = rings.next().unwrap_or_default();
+        Self::new(exterior, rings.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        point::Point3,
+        polygon::{Location, locate_in_ring},
+    };
+
+    #[test]
+    fn test_douglas_peucker() {
+        let line = [
+            Point2::from([0.0, 0.0]),
+            Point2::from([1.0, 0.1]),
+            Point2::from([2.0, -0.1]),
+            Point2::from([3.0, 5.0]),
+            Point2::from([4.0, 6.0]),
+            Point2::from([5.0, 7.0]),
+            Point2::from([6.0, 8.1]),
+            Point2::from([7.0, 9.0]),
+        ];
+        let simplified = simplify_douglas_peucker(&line, 0.5);
+        assert_eq!(
+            simplified,
+            [
+                Point2::from([0.0, 0.0]),
+                Point2::from([2.0, -0.1]),
+                Point2::from([3.0, 5.0]),
+                Point2::from([7.0, 9.0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_douglas_peucker_3d() {
+        let line = [
+            Point3::from([0.0, 0.0, 0.0]),
+            Point3::from([1.0, 1.0, 1.01]),
+            Point3::from([2.0, 2.0, 2.0]),
+            Point3::from([2.0, 2.0, 5.0]),
+        ];
+        let simplified = simplify_douglas_peucker(&line, 0.1);
+        assert_eq!(simplified, [line[0], line[2], line[3]]);
+    }
+
+    #[test]
+    fn test_visvalingam() {
+        let line = [
+            Point2::from([0.0, 0.0]),
+            Point2::from([1.0, 0.1]),
+            Point2::from([2.0, 0.0]),
+            Point2::from([3.0, 3.0]),
+            Point2::from([4.0, 0.0]),
+        ];
+        let simplified = simplify_visvalingam(&line, 0.5);
+        assert_eq!(simplified, [line[0], line[2], line[3], line[4]]);
+        assert_eq!(simplify_visvalingam(&line, 100.0), [line[0], line[4]]);
+    }
+
+    #[test]
+    fn test_topology_preserving() {
+        let exterior = vec![
+            Point2::from([0.0, 0.0]),
+            Point2::from([5.0, -1.0]),
+            Point2::from([10.0, 0.0]),
+            Point2::from([10.0, 10.0]),
+            Point2::from([0.0, 10.0]),
+        ];
+        let hole = vec![
+            Point2::from([4.8, -0.3]),
+            Point2::from([5.0, -0.1]),
+            Point2::from([5.2, -0.3]),
+        ];
+        let polygon = Polygon::new(exterior.clone(), vec![hole.clone()]);
+
+        let simplified = polygon.simplify(10.0);
+        assert_eq!(simplified.exterior, exterior);
+        assert_eq!(simplified.holes, std::slice::from_ref(&hole));
+        assert!(
+            hole.iter()
+                .all(|&p| locate_in_ring(&simplified.exterior, p) == Location::Inside)
+        );
+
+        let unconstrained = Polygon::new(exterior, Vec::new()).simplify(10.0);
+        assert_eq!(unconstrained.exterior.len(), 4);
+    }
+}
@@ -0,0 +1,495 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashSet},
+};
+
+use crate::{
+    edge::{Edge2, SegmentIntersection},
+    point::Point2,
+    scalar::Float,
+};
+
+#[derive(Debug, Clone, Copy)]
+struct EventPoint<T: Float>(Point2<T>);
+
+impl<T: Float> PartialEq for EventPoint<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Float> Eq for EventPoint<T> {}
+
+impl<T: Float> PartialOrd for EventPoint<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for EventPoint<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Event {
+    starts: Vec<usize>,
+    ends: Vec<usize>,
+    interior: Vec<usize>,
+}
+
+const NIL: usize = usize::MAX;
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    left: usize,
+    right: usize,
+    parent: usize,
+    size: usize,
+}
+
+const EMPTY: Node = Node {
+    left: NIL,
+    right: NIL,
+    parent: NIL,
+    size: 0,
+};
+
+struct Status {
+    nodes: Vec<Node>,
+    root: usize,
+}
+
+impl Status {
+    fn new(len: usize) -> Self {
+        Self {
+            nodes: vec![EMPTY; len],
+            root: NIL,
+        }
+    }
+
+    fn priority(t: usize) -> u64 {
+        let mut z = (t as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn size(&self, t: usize) -> usize {
+        if t == NIL { 0 } else { self.nodes[t].size }
+    }
+
+    fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    fn contains(&self, segment: usize) -> bool {
+        self.nodes[segment].size > 0
+    }
+
+    fn update(&mut self, t: usize) {
+        let Node { left, right, .. } = self.nodes[t];
+        self.nodes[t].size = 1 + self.size(left) + self.size(right);
+        for child in [left, right] {
+            if child != NIL {
+                self.nodes[child].parent = t;
+            }
+        }
+    }
+
+    fn merge(&mut self, a: usize, b: usize) -> usize {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+        if Self::priority(a) > Self::priority(b) {
+            self.nodes[a].right = self.merge(self.nodes[a].right, b);
+            self.update(a);
+            a
+        } else {
+            self.nodes[b].left = self.merge(a, self.nodes[b].left);
+            self.update(b);
+            b
+        }
+    }
+
+    fn split(&mut self, t: usize, k: usize) -> (usize, usize) {
+        if t == NIL {
+            return (NIL, NIL);
+        }
+        let left = self.nodes[t].left;
+        if self.size(left) >= k {
+            let (a, b) = self.split(left, k);
+            self.nodes[t].left = b;
+            self.update(t);
+            (a, t)
+        } else {
+            let (a, b) = self.split(self.nodes[t].right, k - self.size(left) - 1);
+            self.nodes[t].right = a;
+            self.update(t);
+            (t, b)
+        }
+    }
+
+    fn set_root(&mut self, t: usize) {
+        self.root = t;
+        if t != NIL {
+            self.nodes[t].parent = NIL;
+        }
+    }
+
+    fn insert(&mut self, k: usize, segment: usize) {
+        self.nodes[segment] = Node { size: 1, ..EMPTY };
+        let (a, b) = self.split(self.root, k);
+        let a = self.merge(a, segment);
+        let root = self.merge(a, b);
+        self.set_root(root);
+    }
+
+    fn remove(&mut self, k: usize) -> usize {
+        let (a, b) = self.split(self.root, k);
+        let (segment, c) = self.split(b, 1);
+        let root = self.merge(a, c);
+        self.set_root(root);
+        self.nodes[segment] = EMPTY;
+        segment
+    }
+
+    fn get(&self, mut k: usize) -> usize {
+        let mut t = self.root;
+        loop {
+            let left = self.size(self.nodes[t].left);
+            match k.cmp(&left) {
+                Ordering::Less => t = self.nodes[t].left,
+                Ordering::Equal => return t,
+                Ordering::Greater => {
+                    k -= left + 1;
+                    t = self.nodes[t].right;
+                }
+            }
+        }
+    }
+
+    fn rank(&self, segment: usize) -> usize {
+        let mut rank = self.size(self.nodes[segment].left);
+        let mut t = segment;
+        while self.nodes[t].parent != NIL {
+            let parent = self.nodes[t].parent;
+            if self.nodes[parent].right == t {
+                rank += self.size(self.nodes[parent].left) + 1;
+            }
+            t = parent;
+        }
+        rank
+    }
+
+    fn partition_point(&self, mut pred: impl FnMut(usize) -> bool) -> usize {
+        let (mut t, mut k) = (self.root, 0);
+        while t != NIL {
+            if pred(t) {
+                k += self.size(self.nodes[t].left) + 1;
+                t = self.nodes[t].right;
+            } else {
+                t = self.nodes[t].left;
+            }
+        }
+        k
+    }
+}
+
+struct Sweep<'a, T: Float> {
+    edges: &'a [Edge2<T>],
+    events: BTreeMap<EventPoint<T>, Event>,
+    status: Status,
+    current: Vec<usize>,
+    candidates: HashSet<(usize, usize)>,
+}
+
+impl<'a, T: Float> Sweep<'a, T> {
+    fn new(edges: &'a [Edge2<T>]) -> Self {
+        let mut events: BTreeMap<_, Event> = BTreeMap::new();
+        for (i, e) in edges.iter().enumerate() {
+            events.entry(EventPoint(e.a)).or_default().starts.push(i);
+            events.entry(EventPoint(e.b)).or_default().ends.push(i);
+        }
+        Self {
+            edges,
+            events,
+            status: Status::new(edges.len()),
+            current: Vec::new(),
+            candidates: HashSet::new(),
+        }
+    }
+
+    fn y_at(&self, i: usize, p: Point2<T>) -> T {
+        let Edge2 { a, b } = self.edges[i];
+        if a.x == b.x {
+            p.y.max(a.y).min(b.y)
+        } else if p.x == a.x {
+            a.y
+        } else if p.x == b.x {
+            b.y
+        } else {
+            a.y + (p.x - a.x) * (b.y - a.y) / (b.x - a.x)
+        }
+    }
+
+    fn cmp_slopes(&self, i: usize, j: usize) -> Ordering {
+        let u = self.edges[i].vector();
+        let v = self.edges[j].vector();
+        (u.y * v.x).total_cmp(&(v.y * u.x)).then_with(|| i.cmp(&j))
+    }
+
+    fn lower_bound(&self, p: Point2<T>) -> usize {
+        self.status.partition_point(|s| self.y_at(s, p) < p.y)
+    }
+
+    fn position(&self, i: usize) -> Option<usize> {
+        self.status.contains(i).then(|| self.status.rank(i))
+    }
+
+    fn containing(&self, p: Point2<T>) -> Vec<usize> {
+        (self.lower_bound(p)..self.status.len())
+            .map(|k| self.status.get(k))
+            .take_while(|&s| self.edges[s].contains_point(p))
+            .collect()
+    }
+
+    fn tolerance(p: Point2<T>) -> T {
+        T::EPSILON * T::from(64.0) * (T::one() + p.x.abs().max(p.y.abs()))
+    }
+
+    fn is_near(p: Point2<T>, q: Point2<T>) -> bool {
+        let tolerance = Self::tolerance(p);
+        (p.x - q.x).abs() <= tolerance && (p.y - q.y).abs() <= tolerance
+    }
+
+    fn snap(&self, q: Point2<T>) -> Point2<T> {
+        let tolerance = Self::tolerance(q);
+        let infinity = T::from(f64::INFINITY);
+        let lo = EventPoint(Point2::from([q.x - tolerance, T::zero() - infinity]));
+        let hi = EventPoint(Point2::from([q.x + tolerance, infinity]));
+        self.events
+            .range(lo..=hi)
+            .map(|(EventPoint(e), _)| *e)
+            .find(|&e| Self::is_near(e, q))
+            .unwrap_or(q)
+    }
+
+    fn pair(i: usize, j: usize) -> (usize, usize) {
+        (i.min(j), i.max(j))
+    }
+
+    fn check(&mut self, k: usize, p: Point2<T>) {
+        let (below, above) = (self.status.get(k), self.status.get(k + 1));
+        let q = match self.edges[below].intersection(&self.edges[above]) {
+            SegmentIntersection::None => return,
+            SegmentIntersection::Point(q) => q,
+            SegmentIntersection::Overlap(e) => e.a.min(e.b),
+        };
+        self.candidates.insert(Self::pair(below, above));
+        let is_near = Self::is_near(p, q);
+        if is_near {
+            for &m in &self.current {
+                for s in [below, above] {
+                    if m != s {
+                        self.candidates.insert(Self::pair(m, s));
+                    }
+                }
+            }
+            self.current.extend([below, above]);
+        }
+        if !is_near && EventPoint(q) > EventPoint(p) {
+            let q = self.snap(q);
+            let event = self.events.entry(EventPoint(q)).or_default();
+            for s in [below, above] {
+                if self.edges[s].a != q && self.edges[s].b != q {
+                    event.interior.push(s);
+                }
+            }
+        } else if self.cmp_slopes(below, above) == Ordering::Greater {
+            let s = self.status.remove(k + 1);
+            self.status.insert(k, s);
+            if k > 0 {
+                self.check(k - 1, p);
+            }
+            if k + 2 < self.status.len() {
+                self.check(k + 1, p);
+            }
+        }
+    }
+
+    fn handle(&mut self, p: Point2<T>, event: Event) {
+        let mut through = event.interior;
+        through.extend(self.containing(p));
+        through.retain(|&s| !event.ends.contains(&s) && !event.starts.contains(&s));
+        through.sort_unstable();
+        through.dedup();
+
+        self.current = event
+            .starts
+            .iter()
+            .chain(&event.ends)
+            .chain(&through)
+            .copied()
+            .collect();
+        self.current.sort_unstable();
+        self.current.dedup();
+        for (n, &i) in self.current.iter().enumerate() {
+            for &j in &self.current[n + 1..] {
+                self.candidates.insert((i, j));
+            }
+        }
+
+        let mut exposed = Vec::new();
+        for &s in event.ends.iter().chain(&through) {
+            if let Some(k) = self.position(s) {
+                self.status.remove(k);
+                if k > 0 {
+                    exposed.push(self.status.get(k - 1));
+                }
+            }
+        }
+
+        let mut inserted = event
+            .starts
+            .iter()
+            .chain(&through)
+            .copied()
+            .filter(|s| !event.ends.contains(s))
+            .collect::<Vec<_>>();
+        inserted.sort_by(|&i, &j| self.cmp_slopes(i, j));
+        let lo = self.lower_bound(p);
+        let hi = lo + inserted.len();
+        for (k, s) in (lo..).zip(inserted) {
+            self.status.insert(k, s);
+        }
+
+        if hi < self.status.len() && hi > 0 {
+            self.check(hi - 1, p);
+        }
+        if lo < hi && lo > 0 {
+            self.check(lo - 1, p);
+        }
+        for s in exposed {
+            if let Some(k) = self.position(s)
+                && k + 1 < self.status.len()
+            {
+                self.check(k, p);
+            }
+        }
+    }
+
+    fn run(mut self) -> HashSet<(usize, usize)> {
+        while let Some((EventPoint(p), event)) = self.events.pop_first() {
+            self.handle(p, event);
+        }
+        self.candidates
+    }
+}
+
+#[must_use]
+pub fn segment_intersections<T: Float>(
+    edges: &[Edge2<T>],
+) -> Vec<(usize, usize, SegmentIntersection<T>)> {
+    let oriented = edges
+        .iter()
+        .map(|e| {
+            if e.a.total_cmp(&e.b) == Ordering::Greater {
+                e.flip()
+            } else {
+                *e
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut pairs = Sweep::new(&oriented).run().into_iter().collect::<Vec<_>>();
+    pairs.sort_unstable();
+    pairs
+        .into_iter()
+        .filter_map(|(i, j)| match edges[i].intersection(&edges[j]) {
+            SegmentIntersection::None => None,
+            intersection => Some((i, j, intersection)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::random_unit;
+
+    fn edge(a: [f64; 2], b: [f64; 2]) -> Edge2<f64> {
+        Edge2::new(Point2::from(a), Point2::from(b))
+    }
+
+    fn brute_force(edges: &[Edge2<f64>]) -> Vec<(usize, usize, SegmentIntersection<f64>)> {
+        let mut result = Vec::new();
+        for i in 0..edges.len() {
+            for j in i + 1..edges.len() {
+                match edges[i].intersection(&edges[j]) {
+                    SegmentIntersection::None => {}
+                    intersection => result.push((i, j, intersection)),
+                }
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_simple_cross() {
+        let edges = [
+            edge([0.0, 0.0], [2.0, 2.0]),
+            edge([0.0, 2.0], [2.0, 0.0]),
+            edge([3.0, 0.0], [4.0, 0.0]),
+        ];
+        assert_eq!(
+            segment_intersections(&edges),
+            [(0, 1, SegmentIntersection::Point(Point2::from([1.0, 1.0])))]
+        );
+    }
+
+    #[test]
+    fn test_degenerate_cases() {
+        let edges = [
+            edge([0.0, 0.0], [4.0, 0.0]),
+            edge([2.0, -1.0], [2.0, 3.0]),
+            edge([4.0, 0.0], [4.0, 2.0]),
+            edge([1.0, 0.0], [3.0, 0.0]),
+            edge([3.0, 0.0], [5.0, 0.0]),
+            edge([0.0, 2.0], [4.0, 2.0]),
+            edge([2.0, 2.0], [2.0, 2.0]),
+            edge([5.0, 0.0], [0.0, 0.0]),
+        ];
+        assert_eq!(segment_intersections(&edges), brute_force(&edges));
+    }
+
+    #[test]
+    fn test_grid_against_brute_force() {
+        let mut state = 12345u64;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1);
+            <f64 as From<u32>>::from(u32::try_from(state >> 60).unwrap())
+        };
+        let edges = (0..200)
+            .map(|_| edge([next(), next()], [next(), next()]))
+            .collect::<Vec<_>>();
+        assert_eq!(segment_intersections(&edges), brute_force(&edges));
+    }
+
+    #[test]
+    fn test_random_segments_against_brute_force() {
+        let mut next = random_unit(777);
+        let edges = (0..1500)
+            .map(|_| {
+                let (x, y) = (next() * 100.0, next() * 100.0);
+                edge([x, y], [x + next() * 8.0 - 4.0, y + next() * 8.0 - 4.0])
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(segment_intersections(&edges), brute_force(&edges));
+    }
+}
@@ -0,0 +1,9 @@
+pub fn random_unit(seed: u64) -> impl FnMut() -> f64 {
+    let mut state = seed;
+    move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        <f64 as From<u32>>::from(u32::try_from(state >> 40).unwrap()) / 16_777_216.0
+    }
+}
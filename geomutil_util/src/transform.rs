@@ -0,0 +1,439 @@
+use std::ops::Mul;
+
+use crate::{
+    bounding_box::{BoundingBox2, BoundingBox3},
+    edge::{Edge2, Edge3},
+    point::{Point2, Point3},
+    scalar::Float,
+    shape2::Shape2D,
+    triangle::Triangle,
+    triangle3::Triangle3,
+};
+
+fn identity<const M: usize, T: Float>() -> [[T; M]; M] {
+    let mut m = [[T::zero(); M]; M];
+    for (i, row) in m.iter_mut().enumerate() {
+        row[i] = T::one();
+    }
+    m
+}
+
+fn multiply<const M: usize, T: Float>(a: &[[T; M]; M], b: &[[T; M]; M]) -> [[T; M]; M] {
+    let mut m = [[T::zero(); M]; M];
+    for (i, row) in m.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..M).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    m
+}
+
+fn invert<const M: usize, T: Float>(m: &[[T; M]; M]) -> Option<[[T; M]; M]> {
+    let mut a = *m;
+    let mut inverse = identity::<M, T>();
+    for col in 0..M {
+        let pivot = (col..M).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col] == T::zero() {
+            return None;
+        }
+        a.swap(col, pivot);
+        inverse.swap(col, pivot);
+        let scale = T::one() / a[col][col];
+        for j in 0..M {
+            a[col][j] *= scale;
+            inverse[col][j] *= scale;
+        }
+        for row in 0..M {
+            if row == col {
+                continue;
+            }
+            let factor = a[row][col];
+            if factor == T::zero() {
+                continue;
+            }
+            for j in 0..M {
+                a[row][j] -= factor * a[col][j];
+                inverse[row][j] -= factor * inverse[col][j];
+            }
+        }
+    }
+    Some(inverse)
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2<T: Float> {
+    pub matrix: [[T; 3]; 3],
+}
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform3<T: Float> {
+    pub matrix: [[T; 4]; 4],
+}
+
+impl<T: Float> Default for Transform2<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Float> Default for Transform3<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Float> Mul for Transform2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from(multiply(&self.matrix, &rhs.matrix))
+    }
+}
+
+impl<T: Float> Mul for Transform3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from(multiply(&self.matrix, &rhs.matrix))
+    }
+}
+
+impl<T: Float> From<[[T; 3]; 3]> for Transform2<T> {
+    fn from(matrix: [[T; 3]; 3]) -> Self {
+        Self { matrix }
+    }
+}
+
+impl<T: Float> From<[[T; 4]; 4]> for Transform3<T> {
+    fn from(matrix: [[T; 4]; 4]) -> Self {
+        Self { matrix }
+    }
+}
+
+impl<T: Float> Transform2<T> {
+    #[must_use]
+    pub fn identity() -> Self {
+        Self::from(identity())
+    }
+
+    #[must_use]
+    pub fn translation(offset: Point2<T>) -> Self {
+        let mut t = Self::identity();
+        t.matrix[0][2] = offset.x;
+        t.matrix[1][2] = offset.y;
+        t
+    }
+
+    #[must_use]
+    pub fn scaling(factors: Point2<T>) -> Self {
+        let mut t = Self::identity();
+        t.matrix[0][0] = factors.x;
+        t.matrix[1][1] = factors.y;
+        t
+    }
+
+    #[must_use]
+    pub fn rotation(angle: T) -> Self {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let mut t = Self::identity();
+        t.matrix[0][0] = cos;
+        t.matrix[0][1] = T::zero() - sin;
+        t.matrix[1][0] = sin;
+        t.matrix[1][1] = cos;
+        t
+    }
+
+    #[must_use]
+    pub fn rotation_about(center: Point2<T>, angle: T) -> Self {
+        Self::translation(center)
+            * Self::rotation(angle)
+            * Self::translation(Point2::zero() - center)
+    }
+
+    #[must_use]
+    pub fn then(self, other: Self) -> Self {
+        other * self
+    }
+
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        invert(&self.matrix).map(Self::from)
+    }
+
+    #[must_use]
+    pub fn apply_vector(&self, v: Point2<T>) -> Point2<T> {
+        let m = &self.matrix;
+        Point2::from([m[0][0] * v.x + m[0][1] * v.y, m[1][0] * v.x + m[1][1] * v.y])
+    }
+
+    #[must_use]
+    pub fn apply_point(&self, p: Point2<T>) -> Point2<T> {
+        let m = &self.matrix;
+        let w = m[2][0] * p.x + m[2][1] * p.y + m[2][2];
+        (self.apply_vector(p) + Point2::from([m[0][2], m[1][2]])) / w
+    }
+
+    #[must_use]
+    pub fn apply_edge(&self, e: &Edge2<T>) -> Edge2<T> {
+        Edge2::new(self.apply_point(e.a), self.apply_point(e.b))
+    }
+
+    #[must_use]
+    pub fn apply_triangle(&self, t: &Triangle<T>) -> Triangle<T> {
+        Triangle::new(
+            self.apply_point(t.a),
+            self.apply_point(t.b),
+            self.apply_point(t.c),
+        )
+    }
+
+    #[must_use]
+    pub fn apply_bounding_box(&self, bbox: &BoundingBox2<T>) -> BoundingBox2<T> {
+        Point2::bounding_box(bbox.corners().map(|p| self.apply_point(p))).unwrap_or(*bbox)
+    }
+
+    #[must_use]
+    pub fn apply_shape(&self, shape: &Shape2D<T>) -> Shape2D<T> {
+        Shape2D::new(
+            shape
+                .triangles
+                .iter()
+                .map(|t| self.apply_triangle(t))
+                .collect(),
+        )
+    }
+}
+
+impl<T: Float> Transform3<T> {
+    #[must_use]
+    pub fn identity() -> Self {
+        Self::from(identity())
+    }
+
+    #[must_use]
+    pub fn translation(offset: Point3<T>) -> Self {
+        let mut t = Self::identity();
+        for i in 0..3 {
+            t.matrix[i][3] = offset[i];
+        }
+        t
+    }
+
+    #[must_use]
+    pub fn scaling(factors: Point3<T>) -> Self {
+        let mut t = Self::identity();
+        for i in 0..3 {
+            t.matrix[i][i] = factors[i];
+        }
+        t
+    }
+
+    #[must_use]
+    pub fn rotation_x(angle: T) -> Self {
+        Self::rotation(Point3::from([T::one(), T::zero(), T::zero()]), angle)
+    }
+
+    #[must_use]
+    pub fn rotation_y(angle: T) -> Self {
+        Self::rotation(Point3::from([T::zero(), T::one(), T::zero()]), angle)
+    }
+
+    #[must_use]
+    pub fn rotation_z(angle: T) -> Self {
+        Self::rotation(Point3::from([T::zero(), T::zero(), T::one()]), angle)
+    }
+
+    #[must_use]
+    pub fn rotation(axis: Point3<T>, angle: T) -> Self {
+        let [ax, ay, az] = axis.normalize().coords;
+        let (sin, cos) = (angle.sin(), angle.cos());
+        let k = T::one() - cos;
+        let mut t = Self::identity();
+        t.matrix[0][0] = cos + ax * ax * k;
+        t.matrix[0][1] = ax * ay * k - az * sin;
+        t.matrix[0][2] = ax * az * k + ay * sin;
+        t.matrix[1][0] = ay * ax * k + az * sin;
+        t.matrix[1][1] = cos + ay * ay * k;
+        t.matrix[1][2] = ay * az * k - ax * sin;
+        t.matrix[2][0] = az * ax * k - ay * sin;
+        t.matrix[2][1] = az * ay * k + ax * sin;
+        t.matrix[2][2] = cos + az * az * k;
+        t
+    }
+
+    #[must_use]
+    pub fn rotation_about(center: Point3<T>, axis: Point3<T>, angle: T) -> Self {
+        Self::translation(center)
+            * Self::rotation(axis, angle)
+            * Self::translation(Point3::zero() - center)
+    }
+
+    #[must_use]
+    pub fn then(self, other: Self) -> Self {
+        other * self
+    }
+
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        invert(&self.matrix).map(Self::from)
+    }
+
+    #[must_use]
+    pub fn apply_vector(&self, v: Point3<T>) -> Point3<T> {
+        let m = &self.matrix;
+        Point3::from([0, 1, 2].map(|i| m[i][0] * v.x + m[i][1] * v.y + m[i][2] * v.z))
+    }
+
+    #[must_use]
+    pub fn apply_point(&self, p: Point3<T>) -> Point3<T> {
+        let m = &self.matrix;
+        let w = m[3][0] * p.x + m[3][1] * p.y + m[3][2] * p.z + m[3][3];
+        (self.apply_vector(p) + Point3::from([m[0][3], m[1][3], m[2][3]])) / w
+    }
+
+    #[must_use]
+    pub fn apply_edge(&self, e: &Edge3<T>) -> Edge3<T> {
+        Edge3::new(self.apply_point(e.a), self.apply_point(e.b))
+    }
+
+    #[must_use]
+    pub fn apply_triangle(&self, t: &Triangle3<T>) -> Triangle3<T> {
+        Triangle3::new(
+            self.apply_point(t.a),
+            self.apply_point(t.b),
+            self.apply_point(t.c),
+        )
+    }
+
+    #[must_use]
+    pub fn apply_bounding_box(&self, bbox: &BoundingBox3<T>) -> BoundingBox3<T> {
+        Point3::bounding_box(bbox.corners().map(|p| self.apply_point(p))).unwrap_or(*bbox)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::*;
+
+    fn assert_point_approx_eq<const N: usize>(
+        a: crate::point::Point<N, f64>,
+        b: crate::point::Point<N, f64>,
+    ) {
+        assert!(a.distance(b) < 1e-10, "wanted: {b:?}, got: {a:?}");
+    }
+
+    #[test]
+    fn test_transform2() {
+        let p = Point2::from([1.0, 0.0]);
+        assert_point_approx_eq(
+            Transform2::rotation(FRAC_PI_2).apply_point(p),
+            Point2::from([0.0, 1.0]),
+        );
+        let t = Transform2::scaling(Point2::from([2.0, 3.0]))
+            .then(Transform2::translation(Point2::from([1.0, 1.0])));
+        assert_eq!(
+            t.apply_point(Point2::from([1.0, 1.0])),
+            Point2::from([3.0, 4.0])
+        );
+        assert_eq!(
+            t.apply_vector(Point2::from([1.0, 1.0])),
+            Point2::from([2.0, 3.0])
+        );
+
+        let about = Transform2::rotation_about(Point2::from([1.0, 1.0]), FRAC_PI_2);
+        assert_point_approx_eq(
+            about.apply_point(Point2::from([2.0, 1.0])),
+            Point2::from([1.0, 2.0]),
+        );
+
+        let inverse = t.inverse().unwrap();
+        assert_point_approx_eq(inverse.apply_point(t.apply_point(p)), p);
+        assert_eq!((inverse * t).matrix, Transform2::identity().matrix);
+        assert!(
+            Transform2::scaling(Point2::from([0.0, 1.0]))
+                .inverse()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_transform2_geometry() {
+        let t = Transform2::rotation(FRAC_PI_2);
+        let bbox = BoundingBox2::new(Point2::from([0.0, 0.0]), Point2::from([2.0, 1.0]));
+        let rotated = t.apply_bounding_box(&bbox);
+        assert_point_approx_eq(rotated.lower, Point2::from([-1.0, 0.0]));
+        assert_point_approx_eq(rotated.upper, Point2::from([0.0, 2.0]));
+
+        let triangle = Triangle::new(
+            Point2::from([0.0, 0.0]),
+            Point2::from([2.0, 0.0]),
+            Point2::from([0.0, 2.0]),
+        );
+        let shifted = Transform2::translation(Point2::from([1.0, 1.0])).apply_triangle(&triangle);
+        assert_eq!(shifted.circumcenter(), Point2::from([2.0, 2.0]));
+        let shape = Transform2::scaling(Point2::from([2.0, 2.0]))
+            .apply_shape(&Shape2D::new(vec![triangle]));
+        assert!((shape.area() - 8.0).abs() < 1e-10);
+        let edge = t.apply_edge(&Edge2::new(
+            Point2::from([1.0, 0.0]),
+            Point2::from([2.0, 0.0]),
+        ));
+        assert_point_approx_eq(edge.b, Point2::from([0.0, 2.0]));
+    }
+
+    #[test]
+    fn test_transform3() {
+        let p = Point3::from([1.0, 0.0, 0.0]);
+        assert_point_approx_eq(
+            Transform3::rotation_z(FRAC_PI_2).apply_point(p),
+            Point3::from([0.0, 1.0, 0.0]),
+        );
+        assert_point_approx_eq(
+            Transform3::rotation_y(FRAC_PI_2).apply_point(p),
+            Point3::from([0.0, 0.0, -1.0]),
+        );
+        assert_point_approx_eq(
+            Transform3::rotation_x(FRAC_PI_2).apply_point(Point3::from([0.0, 1.0, 0.0])),
+            Point3::from([0.0, 0.0, 1.0]),
+        );
+        let axis = Transform3::rotation(
+            Point3::from([1.0, 1.0, 1.0]),
+            2.0 * std::f64::consts::FRAC_PI_3,
+        );
+        assert_point_approx_eq(axis.apply_point(p), Point3::from([0.0, 1.0, 0.0]));
+
+        let about = Transform3::rotation_about(
+            Point3::from([1.0, 1.0, 0.0]),
+            Point3::from([0.0, 0.0, 1.0]),
+            FRAC_PI_2,
+        );
+        assert_point_approx_eq(
+            about.apply_point(Point3::from([2.0, 1.0, 5.0])),
+            Point3::from([1.0, 2.0, 5.0]),
+        );
+
+        let t = Transform3::translation(Point3::from([1.0, 2.0, 3.0])) * axis;
+        let inverse = t.inverse().unwrap();
+        assert_point_approx_eq(inverse.apply_point(t.apply_point(p)), p);
+
+        let bbox = BoundingBox3::new(Point3::from([0.0, 0.0, 0.0]), Point3::from([1.0, 2.0, 3.0]));
+        let moved = Transform3::rotation_z(FRAC_PI_2).apply_bounding_box(&bbox);
+        assert_point_approx_eq(moved.lower, Point3::from([-2.0, 0.0, 0.0]));
+        assert_point_approx_eq(moved.upper, Point3::from([0.0, 1.0, 3.0]));
+
+        let triangle = Triangle3::new(
+            p,
+            Point3::from([0.0, 1.0, 0.0]),
+            Point3::from([0.0, 0.0, 1.0]),
+        );
+        let scaled = Transform3::scaling(Point3::from([2.0, 2.0, 2.0])).apply_triangle(&triangle);
+        assert!((scaled.area() - 4.0 * triangle.area()).abs() < 1e-10);
+        let edge =
+            Transform3::translation(Point3::from([0.0, 0.0, 1.0])).apply_edge(&Edge3::new(p, p));
+        assert_eq!(edge.a, Point3::from([1.0, 0.0, 1.0]));
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{edge::Edge2, point::Point2, scalar::Float};
+use std::ops::{Add, Mul};
+
+use crate::{
+    bounding_box::BoundingBox2, edge::Edge2, point::Point2, polygon::Location, scalar::Float,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
@@ -87,6 +91,11 @@ impl<T: Float> Triangle<T> {
         ]
     }
 
+    #[must_use]
+    pub fn bounding_box(&self) -> BoundingBox2<T> {
+        BoundingBox2::new(self.a, self.b).expand_to_point(self.c)
+    }
+
     #[must_use]
     pub fn perimeter(&self) -> T {
         self.edges().iter().map(Edge2::length).sum()
@@ -101,4 +110,268 @@ impl<T: Float> Triangle<T> {
         let s = T::from(0.5) * (a + b + c);
         (s * (s - a) * (s - b) * (s - c)).sqrt()
     }
+
+    #[must_use]
+    pub fn angles(&self) -> [T; 3] {
+        let angle = |o: Point2<T>, p: Point2<T>, q: Point2<T>| {
+            let (u, v) = (p - o, q - o);
+            u.cross(v).abs().atan2(u.dot(v))
+        };
+        [
+            angle(self.a, self.b, self.c),
+            angle(self.b, self.c, self.a),
+            angle(self.c, self.a, self.b),
+        ]
+    }
+
+    #[must_use]
+    pub fn min_angle(&self) -> T {
+        self.angles().into_iter().fold(T::PI, T::min)
+    }
+
+    #[must_use]
+    pub fn max_angle(&self) -> T {
+        self.angles().into_iter().fold(T::zero(), T::max)
+    }
+
+    #[must_use]
+    pub fn shortest_edge(&self) -> T {
+        self.edges()
+            .iter()
+            .map(Edge2::length)
+            .fold(T::from(f64::INFINITY), T::min)
+    }
+
+    #[must_use]
+    pub fn longest_edge(&self) -> T {
+        self.edges()
+            .iter()
+            .map(Edge2::length)
+            .fold(T::zero(), T::max)
+    }
+
+    #[must_use]
+    pub fn inradius(&self) -> T {
+        T::from(2.0) * self.signed_area().abs() / self.perimeter()
+    }
+
+    #[must_use]
+    pub fn incenter(&self) -> Point2<T> {
+        let a = self.b.distance(self.c);
+        let b = self.c.distance(self.a);
+        let c = self.a.distance(self.b);
+        (self.a * a + self.b * b + self.c * c) / (a + b + c)
+    }
+
+    #[must_use]
+    pub fn centroid(&self) -> Point2<T> {
+        (self.a + self.b + self.c) / T::from(3.0)
+    }
+
+    #[must_use]
+    pub fn orthocenter(&self) -> Point2<T> {
+        self.a + self.b + self.c - self.circumcenter * T::from(2.0)
+    }
+
+    #[must_use]
+    pub fn aspect_ratio(&self) -> T {
+        self.longest_edge() / (T::from(12.0).sqrt() * self.inradius())
+    }
+
+    #[must_use]
+    pub fn radius_edge_ratio(&self) -> T {
+        self.circumcircle_radius() / self.shortest_edge()
+    }
+
+    #[must_use]
+    pub fn signed_area(&self) -> T {
+        T::from(0.5) * Edge2::new(self.a, self.b).orientation(self.c)
+    }
+
+    #[must_use]
+    pub fn barycentric(&self, p: Point2<T>) -> Option<[T; 3]> {
+        let double_area = Edge2::new(self.a, self.b).orientation(self.c);
+        if double_area == T::zero() {
+            return None;
+        }
+        let u = Edge2::new(self.b, self.c).orientation(p) / double_area;
+        let v = Edge2::new(self.c, self.a).orientation(p) / double_area;
+        Some([u, v, T::one() - u - v])
+    }
+
+    #[must_use]
+    pub fn from_barycentric(&self, [u, v, w]: [T; 3]) -> Point2<T> {
+        self.a * u + self.b * v + self.c * w
+    }
+
+    #[must_use]
+    pub fn locate(&self, p: Point2<T>) -> Location {
+        let edges = self.edges();
+        if edges.iter().any(|e| e.contains_point(p)) {
+            return Location::Boundary;
+        }
+        let orientation = edges[0].orientation(self.c);
+        if orientation == T::zero() {
+            return Location::Outside;
+        }
+        let is_inside = edges.iter().all(|e| {
+            let side = e.orientation(p);
+            (side > T::zero()) == (orientation > T::zero())
+        });
+        if is_inside {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, p: Point2<T>) -> bool {
+        self.locate(p) != Location::Outside
+    }
+
+    #[must_use]
+    pub fn closest_point(&self, p: Point2<T>) -> Point2<T> {
+        if self.locate(p) == Location::Inside {
+            return p;
+        }
+        self.edges()
+            .iter()
+            .map(|e| e.closest_point(p))
+            .min_by(|a, b| a.distance_squared(p).total_cmp(&b.distance_squared(p)))
+            .unwrap_or(p)
+    }
+
+    #[must_use]
+    pub fn distance_to_point(&self, p: Point2<T>) -> T {
+        self.closest_point(p).distance(p)
+    }
+
+    #[must_use]
+    pub fn interpolate<V>(&self, p: Point2<T>, values: [V; 3]) -> Option<V>
+    where
+        V: Copy + Add<Output = V> + Mul<T, Output = V>,
+    {
+        let [u, v, w] = self.barycentric(p)?;
+        Some(values[0] * u + values[1] * v + values[2] * w)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-10, "wanted: {b}, got: {a}");
+    }
+
+    fn triangle() -> Triangle<f64> {
+        Triangle::new(
+            Point2::from([0.0, 0.0]),
+            Point2::from([4.0, 0.0]),
+            Point2::from([0.0, 4.0]),
+        )
+    }
+
+    #[test]
+    fn test_quality_metrics() {
+        let t = triangle();
+        let [a, b, c] = t.angles();
+        assert_approx_eq(a, std::f64::consts::FRAC_PI_2);
+        assert_approx_eq(b, std::f64::consts::FRAC_PI_4);
+        assert_approx_eq(c, std::f64::consts::FRAC_PI_4);
+        assert_approx_eq(t.min_angle(), std::f64::consts::FRAC_PI_4);
+        assert_approx_eq(t.max_angle(), std::f64::consts::FRAC_PI_2);
+        assert_approx_eq(t.inradius(), 4.0 - 8.0f64.sqrt());
+        let incenter = t.incenter();
+        assert_approx_eq(incenter.x, t.inradius());
+        assert_approx_eq(incenter.y, t.inradius());
+        assert_approx_eq(t.radius_edge_ratio(), 8.0f64.sqrt() / 4.0);
+        assert_eq!(t.orthocenter(), t.a);
+
+        let equilateral = Triangle::new(
+            Point2::from([0.0, 0.0]),
+            Point2::from([2.0, 0.0]),
+            Point2::from([1.0, 3.0f64.sqrt()]),
+        );
+        assert_approx_eq(equilateral.aspect_ratio(), 1.0);
+        assert_approx_eq(equilateral.radius_edge_ratio(), 1.0 / 3.0f64.sqrt());
+        assert_approx_eq(equilateral.min_angle(), std::f64::consts::FRAC_PI_3);
+
+        let sliver = Triangle::new(
+            Point2::from([0.0, 0.0]),
+            Point2::from([1.0, 0.0]),
+            Point2::from([0.5, 1e-9]),
+        );
+        assert!((sliver.min_angle() / 2e-9 - 1.0).abs() < 1e-9);
+        assert!((std::f64::consts::PI - sliver.max_angle() - 4e-9).abs() < 1e-15);
+        let centroid = equilateral.centroid();
+        let orthocenter = equilateral.orthocenter();
+        assert_approx_eq(centroid.distance(orthocenter), 0.0);
+        assert_approx_eq(centroid.distance(equilateral.incenter()), 0.0);
+    }
+
+    #[test]
+    fn test_barycentric() {
+        let t = triangle();
+        let [u, v, w] = t.barycentric(Point2::from([1.0, 2.0])).unwrap();
+        assert_approx_eq(u, 0.25);
+        assert_approx_eq(v, 0.25);
+        assert_approx_eq(w, 0.5);
+        assert_eq!(t.from_barycentric([u, v, w]), Point2::from([1.0, 2.0]));
+        assert_eq!(
+            t.bounding_box(),
+            BoundingBox2::new(Point2::from([0.0, 0.0]), Point2::from([4.0, 4.0]))
+        );
+        assert_eq!(t.barycentric(t.b), Some([0.0, 1.0, 0.0]));
+
+        let flat = Triangle::new(t.a, t.b, Point2::from([2.0, 0.0]));
+        assert!(flat.barycentric(Point2::from([1.0, 1.0])).is_none());
+    }
+
+    #[test]
+    fn test_locate() {
+        let t = triangle();
+        let reversed = Triangle::new(t.c, t.b, t.a);
+        for t in [t, reversed] {
+            assert_eq!(t.locate(Point2::from([1.0, 1.0])), Location::Inside);
+            assert_eq!(t.locate(Point2::from([2.0, 2.0])), Location::Boundary);
+            assert_eq!(t.locate(Point2::from([0.0, 4.0])), Location::Boundary);
+            assert_eq!(t.locate(Point2::from([3.0, 3.0])), Location::Outside);
+            assert_eq!(t.locate(Point2::from([-1.0, 1.0])), Location::Outside);
+        }
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let t = triangle();
+        let inside = Point2::from([1.0, 1.0]);
+        assert_eq!(t.closest_point(inside), inside);
+        assert_eq!(
+            t.closest_point(Point2::from([3.0, 3.0])),
+            Point2::from([2.0, 2.0])
+        );
+        assert_eq!(t.closest_point(Point2::from([-1.0, -2.0])), t.a);
+        assert_eq!(
+            t.closest_point(Point2::from([2.0, -3.0])),
+            Point2::from([2.0, 0.0])
+        );
+        assert_approx_eq(t.distance_to_point(Point2::from([2.0, -3.0])), 3.0);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let t = triangle();
+        let value = t.interpolate(Point2::from([1.0, 2.0]), [0.0, 4.0, 8.0]);
+        assert_approx_eq(value.unwrap(), 5.0);
+        let gradient = t.interpolate(
+            Point2::from([2.0, 1.0]),
+            [
+                Point2::zero(),
+                Point2::from([1.0, 0.0]),
+                Point2::from([0.0, 1.0]),
+            ],
+        );
+        assert_eq!(gradient, Some(Point2::from([0.5, 0.25])));
+    }
 }
@@ -0,0 +1,274 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{edge::Edge3, point::Point3, scalar::Float};
+
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Plane<T: Float> {
+    pub normal: Point3<T>,
+    pub offset: T,
+}
+
+impl<T: Float> Plane<T> {
+    #[must_use]
+    pub fn signed_distance(&self, p: Point3<T>) -> T {
+        self.normal.dot(p) - self.offset
+    }
+
+    #[must_use]
+    pub fn project(&self, p: Point3<T>) -> Point3<T> {
+        p - self.normal * self.signed_distance(p)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Triangle3<T: Float> {
+    pub a: Point3<T>,
+    pub b: Point3<T>,
+    pub c: Point3<T>,
+}
+
+impl<T: Float> Triangle3<T> {
+    #[must_use]
+    pub const fn new(a: Point3<T>, b: Point3<T>, c: Point3<T>) -> Self {
+        Self { a, b, c }
+    }
+
+    #[must_use]
+    pub const fn edges(&self) -> [Edge3<T>; 3] {
+        [
+            Edge3::new(self.a, self.b),
+            Edge3::new(self.b, self.c),
+            Edge3::new(self.c, self.a),
+        ]
+    }
+
+    #[must_use]
+    pub fn perimeter(&self) -> T {
+        self.edges().iter().map(Edge3::length).sum()
+    }
+
+    #[must_use]
+    pub fn scaled_normal(&self) -> Point3<T> {
+        (self.b - self.a).cross(self.c - self.a)
+    }
+
+    #[must_use]
+    pub fn normal(&self) -> Point3<T> {
+        self.scaled_normal().normalize()
+    }
+
+    #[must_use]
+    pub fn area(&self) -> T {
+        T::from(0.5) * self.scaled_normal().length()
+    }
+
+    #[must_use]
+    pub fn centroid(&self) -> Point3<T> {
+        (self.a + self.b + self.c) / T::from(3.0)
+    }
+
+    #[must_use]
+    pub fn is_degenerate(&self) -> bool {
+        self.scaled_normal().length_squared() == T::zero()
+    }
+
+    #[must_use]
+    pub fn plane(&self) -> Option<Plane<T>> {
+        if self.is_degenerate() {
+            return None;
+        }
+        let normal = self.normal();
+        Some(Plane {
+            normal,
+            offset: normal.dot(self.a),
+        })
+    }
+
+    #[must_use]
+    pub fn barycentric(&self, p: Point3<T>) -> Option<[T; 3]> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let ap = p - self.a;
+        let d00 = ab.dot(ab);
+        let d01 = ab.dot(ac);
+        let d11 = ac.dot(ac);
+        let d20 = ap.dot(ab);
+        let d21 = ap.dot(ac);
+        let denominator = d00 * d11 - d01.powi(2);
+        if denominator == T::zero() {
+            return None;
+        }
+        let v = (d11 * d20 - d01 * d21) / denominator;
+        let w = (d00 * d21 - d01 * d20) / denominator;
+        Some([T::one() - v - w, v, w])
+    }
+
+    #[must_use]
+    pub fn from_barycentric(&self, [u, v, w]: [T; 3]) -> Point3<T> {
+        self.a * u + self.b * v + self.c * w
+    }
+
+    #[must_use]
+    pub fn closest_point(&self, p: Point3<T>) -> Point3<T> {
+        let zero = T::zero();
+        let (a, b, c) = (self.a, self.b, self.c);
+        let ab = b - a;
+        let ac = c - a;
+        let ap = p - a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= zero && d2 <= zero {
+            return a;
+        }
+        let bp = p - b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= zero && d4 <= d3 {
+            return b;
+        }
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= zero && d1 >= zero && d3 <= zero {
+            return a + ab * (d1 / (d1 - d3));
+        }
+        let cp = p - c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= zero && d5 <= d6 {
+            return c;
+        }
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= zero && d2 >= zero && d6 <= zero {
+            return a + ac * (d2 / (d2 - d6));
+        }
+        let va = d3 * d6 - d5 * d4;
+        if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+        let denominator = va + vb + vc;
+        if denominator == zero {
+            return self
+                .edges()
+                .iter()
+                .map(|e| e.closest_point(p))
+                .min_by(|x, y| x.distance_squared(p).total_cmp(&y.distance_squared(p)))
+                .unwrap_or(a);
+        }
+        a + ab * (vb / denominator) + ac * (vc / denominator)
+    }
+
+    #[must_use]
+    pub fn distance_to_point(&self, p: Point3<T>) -> T {
+        self.closest_point(p).distance(p)
+    }
+
+    #[must_use]
+    pub fn ray_intersection(&self, origin: Point3<T>, direction: Point3<T>) -> Option<T> {
+        let zero = T::zero();
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let p_vec = direction.cross(ac);
+        let det = ab.dot(p_vec);
+        if det == zero {
+            return None;
+        }
+        let inv_det = T::one() / det;
+        let t_vec = origin - self.a;
+        let u = inv_det * t_vec.dot(p_vec);
+        if u < zero || u > T::one() {
+            return None;
+        }
+        let q_vec = t_vec.cross(ab);
+        let v = inv_det * direction.dot(q_vec);
+        if v < zero || u + v > T::one() {
+            return None;
+        }
+        let t = inv_det * ac.dot(q_vec);
+        (t >= zero).then_some(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-10, "wanted: {b}, got: {a}");
+    }
+
+    fn triangle() -> Triangle3<f64> {
+        Triangle3::new(
+            Point3::from([0.0, 0.0, 1.0]),
+            Point3::from([2.0, 0.0, 1.0]),
+            Point3::from([0.0, 2.0, 1.0]),
+        )
+    }
+
+    #[test]
+    fn test_normal_area_plane() {
+        let t = triangle();
+        assert_eq!(t.normal(), Point3::from([0.0, 0.0, 1.0]));
+        assert_approx_eq(t.area(), 2.0);
+        let plane = t.plane().unwrap();
+        assert_approx_eq(plane.offset, 1.0);
+        assert_approx_eq(plane.signed_distance(Point3::from([5.0, 5.0, 3.0])), 2.0);
+        assert_eq!(
+            plane.project(Point3::from([5.0, 5.0, 3.0])),
+            Point3::from([5.0, 5.0, 1.0])
+        );
+
+        let flat = Triangle3::new(t.a, t.b, Point3::from([1.0, 0.0, 1.0]));
+        assert!(flat.plane().is_none());
+        assert!(flat.barycentric(t.c).is_none());
+    }
+
+    #[test]
+    fn test_barycentric() {
+        let t = triangle();
+        let p = Point3::from([0.5, 1.0, 1.0]);
+        let [u, v, w] = t.barycentric(p).unwrap();
+        assert_approx_eq(u, 0.25);
+        assert_approx_eq(v, 0.25);
+        assert_approx_eq(w, 0.5);
+        assert_eq!(t.from_barycentric([u, v, w]), p);
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let t = triangle();
+        assert_eq!(
+            t.closest_point(Point3::from([0.5, 0.5, 4.0])),
+            Point3::from([0.5, 0.5, 1.0])
+        );
+        assert_eq!(t.closest_point(Point3::from([-1.0, -1.0, 0.0])), t.a);
+        assert_eq!(
+            t.closest_point(Point3::from([1.0, -3.0, 1.0])),
+            Point3::from([1.0, 0.0, 1.0])
+        );
+        assert_eq!(
+            t.closest_point(Point3::from([2.0, 2.0, 1.0])),
+            Point3::from([1.0, 1.0, 1.0])
+        );
+        assert_approx_eq(t.distance_to_point(Point3::from([0.5, 0.5, -1.0])), 2.0);
+    }
+
+    #[test]
+    fn test_ray_intersection() {
+        let t = triangle();
+        let down = Point3::from([0.0, 0.0, -1.0]);
+        let hit = t.ray_intersection(Point3::from([0.5, 0.5, 3.0]), down);
+        assert_eq!(hit, Some(2.0));
+        assert_eq!(
+            t.ray_intersection(Point3::from([0.5, 0.5, 0.0]), down),
+            None
+        );
+        assert_eq!(
+            t.ray_intersection(Point3::from([1.5, 1.5, 3.0]), down),
+            None
+        );
+        assert_eq!(
+            t.ray_intersection(Point3::from([0.0, 0.0, 3.0]), Point3::from([1.0, 0.0, 0.0])),
+            None
+        );
+    }
+}